@@ -2,6 +2,7 @@ use crate::util::*;
 use crate::model::*;
 use crate::client::*;
 use crate::errors::*;
+use crate::preflight::{ApiPermission, RequiredPermissions};
 use std::collections::BTreeMap;
 use crate::api::API;
 use crate::api::Spot;
@@ -12,6 +13,12 @@ pub struct Account {
     pub recv_window: u64,
 }
 
+impl RequiredPermissions for Account {
+    fn required_permissions() -> Vec<ApiPermission> {
+        vec![ApiPermission::Reading, ApiPermission::SpotAndMarginTrading]
+    }
+}
+
 struct OrderRequest {
     pub symbol: String,
     pub qty: f64,
@@ -33,6 +40,20 @@ struct OrderQuoteQuantityRequest {
     pub new_client_order_id: Option<String>,
 }
 
+/// Order routed by Smart Order Routing (SOR) across the order books
+/// of the symbols sharing the same base asset.
+///
+/// Only LIMIT and MARKET orders are supported.
+pub struct SorOrderRequest {
+    pub symbol: String,
+    pub qty: f64,
+    pub price: Option<f64>,
+    pub order_side: OrderSide,
+    pub order_type: OrderType,
+    pub time_in_force: Option<TimeInForce>,
+    pub new_client_order_id: Option<String>,
+}
+
 pub enum OrderType {
     Limit,
     Market,
@@ -88,6 +109,36 @@ impl Account {
             .get_signed(API::Spot(Spot::Account), Some(request))
     }
 
+    // Account Information, without the assets that have a zero balance
+    pub fn get_account_non_zero_balances(&self) -> Result<AccountInformation> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("omitZeroBalances".into(), "true".into());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::Account), Some(request))
+    }
+
+    // Current commission rates for ONE symbol
+    pub fn get_commission_rates<S>(&self, symbol: S) -> Result<AccountCommission>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::AccountCommission), Some(request))
+    }
+
+    // Unfilled order count for all order rate limit intervals
+    pub fn get_unfilled_order_count(&self) -> Result<Vec<UnfilledOrderCount>> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::RateLimitOrder), Some(request))
+    }
+
     // Balance for a single Asset
     pub fn get_balance<S>(&self, asset: S) -> Result<Balance>
     where
@@ -658,6 +709,26 @@ impl Account {
             .map(|_| ())
     }
 
+    /// Place an order using Smart Order Routing (SOR)
+    ///
+    /// The fills of the returned `Transaction` carry the allocation ids.
+    pub fn sor_order(&self, order: SorOrderRequest) -> Result<Transaction> {
+        let order = self.build_sor_order(order);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client.post_signed(API::Spot(Spot::SorOrder), request)
+    }
+
+    /// Place a test order using Smart Order Routing (SOR)
+    ///
+    /// This order is sandboxed: it is validated, but not sent to the matching engine.
+    pub fn test_sor_order(&self, order: SorOrderRequest) -> Result<()> {
+        let order = self.build_sor_order(order);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Spot(Spot::SorOrderTest), request)
+            .map(|_| ())
+    }
+
     // Check an order's status
     pub fn cancel_order<S>(&self, symbol: S, order_id: u64) -> Result<OrderCanceled>
     where
@@ -715,6 +786,87 @@ impl Account {
             .get_signed(API::Spot(Spot::MyTrades), Some(request))
     }
 
+    /// Orders that expired because of Self-Trade Prevention, for ONE prevented match id.
+    pub fn get_prevented_match<S>(
+        &self, symbol: S, prevented_match_id: u64,
+    ) -> Result<Vec<PreventedMatch>>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("preventedMatchId".into(), prevented_match_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::MyPreventedMatches), Some(request))
+    }
+
+    /// Orders that expired because of Self-Trade Prevention, for ONE taker order.
+    ///
+    /// Use from_prevented_match_id to page through the results (limit defaults to 500, max 1000).
+    pub fn get_prevented_matches<S1, S2, S3>(
+        &self, symbol: S1, order_id: u64, from_prevented_match_id: S2, limit: S3,
+    ) -> Result<Vec<PreventedMatch>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+        if let Some(fi) = from_prevented_match_id.into() {
+            parameters.insert("fromPreventedMatchId".into(), fi.to_string());
+        }
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), lt.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::MyPreventedMatches), Some(request))
+    }
+
+    /// Allocations resulting from Smart Order Routing (SOR) orders.
+    ///
+    /// The time between start_time and end_time can't be longer than 24 hours.
+    #[allow(clippy::too_many_arguments)]
+    pub fn get_allocations<S1, S2, S3, S4, S5, S6>(
+        &self, symbol: S1, start_time: S2, end_time: S3, from_allocation_id: S4, limit: S5,
+        order_id: S6,
+    ) -> Result<Vec<Allocation>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u16>>,
+        S6: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), st.to_string());
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), et.to_string());
+        }
+        if let Some(fi) = from_allocation_id.into() {
+            parameters.insert("fromAllocationId".into(), fi.to_string());
+        }
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), lt.to_string());
+        }
+        if let Some(oi) = order_id.into() {
+            parameters.insert("orderId".into(), oi.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::MyAllocations), Some(request))
+    }
+
     fn build_order(&self, order: OrderRequest) -> BTreeMap<String, String> {
         let mut order_parameters: BTreeMap<String, String> = BTreeMap::new();
 
@@ -739,6 +891,29 @@ impl Account {
         order_parameters
     }
 
+    fn build_sor_order(&self, order: SorOrderRequest) -> BTreeMap<String, String> {
+        let mut order_parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        order_parameters.insert("symbol".into(), order.symbol);
+        order_parameters.insert("side".into(), order.order_side.into());
+        order_parameters.insert("type".into(), order.order_type.into());
+        order_parameters.insert("quantity".into(), order.qty.to_string());
+
+        if let Some(price) = order.price {
+            order_parameters.insert("price".into(), price.to_string());
+        }
+
+        if let Some(time_in_force) = order.time_in_force {
+            order_parameters.insert("timeInForce".into(), time_in_force.into());
+        }
+
+        if let Some(client_order_id) = order.new_client_order_id {
+            order_parameters.insert("newClientOrderId".into(), client_order_id);
+        }
+
+        order_parameters
+    }
+
     fn build_quote_quantity_order(
         &self, order: OrderQuoteQuantityRequest,
     ) -> BTreeMap<String, String> {
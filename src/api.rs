@@ -5,16 +5,28 @@ use crate::futures::account::FuturesAccount;
 use crate::futures::general::*;
 use crate::futures::market::*;
 use crate::futures::userstream::*;
+use crate::futures::coin_m::account::CoinMAccount;
+use crate::futures::coin_m::market::CoinMMarket;
+use crate::futures::coin_m::userstream::CoinMUserStream;
 use crate::general::*;
+use crate::options::account::OptionsAccount;
+use crate::options::market::OptionsMarket;
+use crate::options::userstream::OptionsUserStream;
 use crate::market::*;
+use crate::margin::*;
 use crate::userstream::*;
 use crate::savings::*;
+use crate::sub_account::*;
+use crate::simple_earn::*;
+use crate::convert::*;
 
 #[allow(clippy::all)]
 pub enum API {
     Spot(Spot),
     Savings(Sapi),
     Futures(Futures),
+    CoinFutures(Dapi),
+    Options(Eapi),
 }
 
 /// Endpoint for production and test orders.
@@ -30,11 +42,16 @@ pub enum Spot {
     AggTrades,
     Klines,
     AvgPrice,
+    UiKlines,
     Ticker24hr,
+    Ticker,
+    TickerTradingDay,
     Price,
     BookTicker,
     Order,
     OrderTest,
+    SorOrder,
+    SorOrderTest,
     OpenOrders,
     AllOrders,
     Oco,
@@ -42,7 +59,11 @@ pub enum Spot {
     AllOrderList,
     OpenOrderList,
     Account,
+    AccountCommission,
+    RateLimitOrder,
     MyTrades,
+    MyPreventedMatches,
+    MyAllocations,
     UserDataStream,
 }
 
@@ -50,6 +71,65 @@ pub enum Sapi {
     AllCoins,
     AssetDetail,
     DepositAddress,
+    Withdraw,
+    DepositHistory,
+    WithdrawHistory,
+    UniversalTransfer,
+    DustBtc,
+    Dust,
+    DustLog,
+    AssetDividend,
+    TradeFee,
+    FundingAsset,
+    UserAsset,
+    ApiRestrictions,
+    AccountStatus,
+    ApiTradingStatus,
+    SubAccountList,
+    SubAccountCreate,
+    SubAccountAssets,
+    SubAccountSpotSummary,
+    SubAccountFuturesSummary,
+    SubAccountUniversalTransfer,
+    SubAccountFuturesEnable,
+    SubAccountMarginEnable,
+    SubAccountIpRestriction,
+    SubAccountIpRestrictionV2,
+    SubAccountIpList,
+    SimpleEarnFlexibleList,
+    SimpleEarnLockedList,
+    SimpleEarnFlexibleSubscribe,
+    SimpleEarnLockedSubscribe,
+    SimpleEarnFlexibleRedeem,
+    SimpleEarnLockedRedeem,
+    SimpleEarnFlexiblePosition,
+    SimpleEarnLockedPosition,
+    SimpleEarnFlexibleSubscriptionRecord,
+    SimpleEarnLockedSubscriptionRecord,
+    SimpleEarnFlexibleRedemptionRecord,
+    SimpleEarnLockedRedemptionRecord,
+    SimpleEarnFlexibleRewardsRecord,
+    SimpleEarnLockedRewardsRecord,
+    SimpleEarnFlexibleCollateralRecord,
+    ConvertExchangeInfo,
+    ConvertGetQuote,
+    ConvertAcceptQuote,
+    ConvertOrderStatus,
+    ConvertTradeFlow,
+    MarginBorrowRepay,
+    MarginAccount,
+    MarginIsolatedAccount,
+    MarginOrder,
+    MarginOpenOrders,
+    MarginAllOrders,
+    MarginOco,
+    MarginOrderList,
+    MarginMyTrades,
+    MarginMaxBorrowable,
+    MarginMaxTransferable,
+    MarginInterestHistory,
+    MarginUserDataStream,
+    IsolatedMarginUserDataStream,
 }
 
 pub enum Futures {
@@ -64,17 +144,36 @@ pub enum Futures {
     ContinuousKlines,
     IndexPriceKlines,
     MarkPriceKlines,
+    PremiumIndexKlines,
     PremiumIndex,
     FundingRate,
+    FundingInfo,
     Ticker24hr,
     TickerPrice,
     BookTicker,
     AllForceOrders,
     AllOpenOrders,
     Order,
+    OpenOrder,
+    AllOrders,
+    BatchOrders,
+    CountdownCancelAll,
+    OrderAmendment,
+    UserTrades,
+    ForceOrders,
     PositionRisk,
     Balance,
     PositionSide,
+    MarginType,
+    PositionMargin,
+    PositionMarginHistory,
+    MultiAssetsMargin,
+    Income,
+    IncomeDownloadId,
+    IncomeDownloadLink,
+    LeverageBracket,
+    AdlQuantile,
+    CommissionRate,
     OpenInterest,
     OpenInterestHist,
     TopLongShortAccountRatio,
@@ -89,6 +188,46 @@ pub enum Futures {
     UserDataStream,
 }
 
+/// Endpoints of the COIN-M futures, margined and settled in the base asset.
+pub enum Dapi {
+    Ping,
+    Time,
+    ExchangeInfo,
+    Depth,
+    Klines,
+    PremiumIndex,
+    TickerPrice,
+    Order,
+    OpenOrders,
+    AllOpenOrders,
+    CountdownCancelAll,
+    Account,
+    Balance,
+    PositionRisk,
+    ChangeInitialLeverage,
+    UserDataStream,
+}
+
+/// Endpoints of the European options.
+pub enum Eapi {
+    ExchangeInfo,
+    Index,
+    Mark,
+    Depth,
+    Klines,
+    Trades,
+    OpenInterest,
+    ExerciseHistory,
+    Order,
+    BatchOrders,
+    Position,
+    UserTrades,
+    MarginAccount,
+    CountdownCancelAll,
+    CountdownCancelAllHeartBeat,
+    UserDataStream,
+}
+
 impl From<API> for String {
     fn from(item: API) -> Self {
         String::from(match item {
@@ -102,11 +241,16 @@ impl From<API> for String {
                 Spot::AggTrades => "/api/v3/aggTrades",
                 Spot::Klines => "/api/v3/klines",
                 Spot::AvgPrice => "/api/v3/avgPrice",
+                Spot::UiKlines => "/api/v3/uiKlines",
                 Spot::Ticker24hr => "/api/v3/ticker/24hr",
+                Spot::Ticker => "/api/v3/ticker",
+                Spot::TickerTradingDay => "/api/v3/ticker/tradingDay",
                 Spot::Price => "/api/v3/ticker/price",
                 Spot::BookTicker => "/api/v3/ticker/bookTicker",
                 Spot::Order => "/api/v3/order",
                 Spot::OrderTest => "/api/v3/order/test",
+                Spot::SorOrder => "/api/v3/sor/order",
+                Spot::SorOrderTest => "/api/v3/sor/order/test",
                 Spot::OpenOrders => "/api/v3/openOrders",
                 Spot::AllOrders => "/api/v3/allOrders",
                 Spot::Oco => "/api/v3/order/oco",
@@ -114,13 +258,92 @@ impl From<API> for String {
                 Spot::AllOrderList => "/api/v3/allOrderList",
                 Spot::OpenOrderList => "/api/v3/openOrderList",
                 Spot::Account => "/api/v3/account",
+                Spot::AccountCommission => "/api/v3/account/commission",
+                Spot::RateLimitOrder => "/api/v3/rateLimit/order",
                 Spot::MyTrades => "/api/v3/myTrades",
+                Spot::MyPreventedMatches => "/api/v3/myPreventedMatches",
+                Spot::MyAllocations => "/api/v3/myAllocations",
                 Spot::UserDataStream => "/api/v3/userDataStream",
             },
             API::Savings(route) => match route {
                 Sapi::AllCoins => "/sapi/v1/capital/config/getall",
                 Sapi::AssetDetail => "/sapi/v1/asset/assetDetail",
                 Sapi::DepositAddress => "/sapi/v1/capital/deposit/address",
+                Sapi::Withdraw => "/sapi/v1/capital/withdraw/apply",
+                Sapi::DepositHistory => "/sapi/v1/capital/deposit/hisrec",
+                Sapi::WithdrawHistory => "/sapi/v1/capital/withdraw/history",
+                Sapi::UniversalTransfer => "/sapi/v1/asset/transfer",
+                Sapi::DustBtc => "/sapi/v1/asset/dust-btc",
+                Sapi::Dust => "/sapi/v1/asset/dust",
+                Sapi::DustLog => "/sapi/v1/asset/dribblet",
+                Sapi::AssetDividend => "/sapi/v1/asset/assetDividend",
+                Sapi::TradeFee => "/sapi/v1/asset/tradeFee",
+                Sapi::FundingAsset => "/sapi/v1/asset/get-funding-asset",
+                Sapi::UserAsset => "/sapi/v3/asset/getUserAsset",
+                Sapi::ApiRestrictions => "/sapi/v1/account/apiRestrictions",
+                Sapi::AccountStatus => "/sapi/v1/account/status",
+                Sapi::ApiTradingStatus => "/sapi/v1/account/apiTradingStatus",
+                Sapi::SubAccountList => "/sapi/v1/sub-account/list",
+                Sapi::SubAccountCreate => "/sapi/v1/sub-account/virtualSubAccount",
+                Sapi::SubAccountAssets => "/sapi/v3/sub-account/assets",
+                Sapi::SubAccountSpotSummary => "/sapi/v1/sub-account/spotSummary",
+                Sapi::SubAccountFuturesSummary => "/sapi/v2/sub-account/futures/accountSummary",
+                Sapi::SubAccountUniversalTransfer => "/sapi/v1/sub-account/universalTransfer",
+                Sapi::SubAccountFuturesEnable => "/sapi/v1/sub-account/futures/enable",
+                Sapi::SubAccountMarginEnable => "/sapi/v1/sub-account/margin/enable",
+                Sapi::SubAccountIpRestriction => "/sapi/v1/sub-account/subAccountApi/ipRestriction",
+                Sapi::SubAccountIpRestrictionV2 => {
+                    "/sapi/v2/sub-account/subAccountApi/ipRestriction"
+                }
+                Sapi::SubAccountIpList => "/sapi/v1/sub-account/subAccountApi/ipRestriction/ipList",
+                Sapi::SimpleEarnFlexibleList => "/sapi/v1/simple-earn/flexible/list",
+                Sapi::SimpleEarnLockedList => "/sapi/v1/simple-earn/locked/list",
+                Sapi::SimpleEarnFlexibleSubscribe => "/sapi/v1/simple-earn/flexible/subscribe",
+                Sapi::SimpleEarnLockedSubscribe => "/sapi/v1/simple-earn/locked/subscribe",
+                Sapi::SimpleEarnFlexibleRedeem => "/sapi/v1/simple-earn/flexible/redeem",
+                Sapi::SimpleEarnLockedRedeem => "/sapi/v1/simple-earn/locked/redeem",
+                Sapi::SimpleEarnFlexiblePosition => "/sapi/v1/simple-earn/flexible/position",
+                Sapi::SimpleEarnLockedPosition => "/sapi/v1/simple-earn/locked/position",
+                Sapi::SimpleEarnFlexibleSubscriptionRecord => {
+                    "/sapi/v1/simple-earn/flexible/history/subscriptionRecord"
+                }
+                Sapi::SimpleEarnLockedSubscriptionRecord => {
+                    "/sapi/v1/simple-earn/locked/history/subscriptionRecord"
+                }
+                Sapi::SimpleEarnFlexibleRedemptionRecord => {
+                    "/sapi/v1/simple-earn/flexible/history/redemptionRecord"
+                }
+                Sapi::SimpleEarnLockedRedemptionRecord => {
+                    "/sapi/v1/simple-earn/locked/history/redemptionRecord"
+                }
+                Sapi::SimpleEarnFlexibleRewardsRecord => {
+                    "/sapi/v1/simple-earn/flexible/history/rewardsRecord"
+                }
+                Sapi::SimpleEarnLockedRewardsRecord => {
+                    "/sapi/v1/simple-earn/locked/history/rewardsRecord"
+                }
+                Sapi::SimpleEarnFlexibleCollateralRecord => {
+                    "/sapi/v1/simple-earn/flexible/history/collateralRecord"
+                }
+                Sapi::ConvertExchangeInfo => "/sapi/v1/convert/exchangeInfo",
+                Sapi::ConvertGetQuote => "/sapi/v1/convert/getQuote",
+                Sapi::ConvertAcceptQuote => "/sapi/v1/convert/acceptQuote",
+                Sapi::ConvertOrderStatus => "/sapi/v1/convert/orderStatus",
+                Sapi::ConvertTradeFlow => "/sapi/v1/convert/tradeFlow",
+                Sapi::MarginBorrowRepay => "/sapi/v1/margin/borrow-repay",
+                Sapi::MarginAccount => "/sapi/v1/margin/account",
+                Sapi::MarginIsolatedAccount => "/sapi/v1/margin/isolated/account",
+                Sapi::MarginOrder => "/sapi/v1/margin/order",
+                Sapi::MarginOpenOrders => "/sapi/v1/margin/openOrders",
+                Sapi::MarginAllOrders => "/sapi/v1/margin/allOrders",
+                Sapi::MarginOco => "/sapi/v1/margin/order/oco",
+                Sapi::MarginOrderList => "/sapi/v1/margin/orderList",
+                Sapi::MarginMyTrades => "/sapi/v1/margin/myTrades",
+                Sapi::MarginMaxBorrowable => "/sapi/v1/margin/maxBorrowable",
+                Sapi::MarginMaxTransferable => "/sapi/v1/margin/maxTransferable",
+                Sapi::MarginInterestHistory => "/sapi/v1/margin/interestHistory",
+                Sapi::MarginUserDataStream => "/sapi/v1/userDataStream",
+                Sapi::IsolatedMarginUserDataStream => "/sapi/v1/userDataStream/isolated",
             },
             API::Futures(route) => match route {
                 Futures::Ping => "/fapi/v1/ping",
@@ -134,15 +357,34 @@ impl From<API> for String {
                 Futures::ContinuousKlines => "/fapi/v1/continuousKlines",
                 Futures::IndexPriceKlines => "/fapi/v1/indexPriceKlines",
                 Futures::MarkPriceKlines => "/fapi/v1/markPriceKlines",
+                Futures::PremiumIndexKlines => "/fapi/v1/premiumIndexKlines",
                 Futures::PremiumIndex => "/fapi/v1/premiumIndex",
                 Futures::FundingRate => "/fapi/v1/fundingRate",
+                Futures::FundingInfo => "/fapi/v1/fundingInfo",
                 Futures::Ticker24hr => "/fapi/v1/ticker/24hr",
                 Futures::TickerPrice => "/fapi/v1/ticker/price",
                 Futures::BookTicker => "/fapi/v1/ticker/bookTicker",
                 Futures::AllForceOrders => "/fapi/v1/allForceOrders",
                 Futures::AllOpenOrders => "/fapi/v1/allOpenOrders",
                 Futures::PositionSide => "/fapi/v1/positionSide/dual",
+                Futures::MarginType => "/fapi/v1/marginType",
+                Futures::PositionMargin => "/fapi/v1/positionMargin",
+                Futures::PositionMarginHistory => "/fapi/v1/positionMargin/history",
+                Futures::MultiAssetsMargin => "/fapi/v1/multiAssetsMargin",
+                Futures::Income => "/fapi/v1/income",
+                Futures::IncomeDownloadId => "/fapi/v1/income/asyn",
+                Futures::IncomeDownloadLink => "/fapi/v1/income/asyn/id",
+                Futures::LeverageBracket => "/fapi/v1/leverageBracket",
+                Futures::AdlQuantile => "/fapi/v1/adlQuantile",
+                Futures::CommissionRate => "/fapi/v1/commissionRate",
                 Futures::Order => "/fapi/v1/order",
+                Futures::OpenOrder => "/fapi/v1/openOrder",
+                Futures::AllOrders => "/fapi/v1/allOrders",
+                Futures::BatchOrders => "/fapi/v1/batchOrders",
+                Futures::CountdownCancelAll => "/fapi/v1/countdownCancelAll",
+                Futures::OrderAmendment => "/fapi/v1/orderAmendment",
+                Futures::UserTrades => "/fapi/v1/userTrades",
+                Futures::ForceOrders => "/fapi/v1/forceOrders",
                 Futures::PositionRisk => "/fapi/v2/positionRisk",
                 Futures::Balance => "/fapi/v2/balance",
                 Futures::OpenInterest => "/fapi/v1/openInterest",
@@ -158,6 +400,42 @@ impl From<API> for String {
                 Futures::OpenOrders => "/fapi/v1/openOrders",
                 Futures::UserDataStream => "/fapi/v1/listenKey",
             },
+            API::CoinFutures(route) => match route {
+                Dapi::Ping => "/dapi/v1/ping",
+                Dapi::Time => "/dapi/v1/time",
+                Dapi::ExchangeInfo => "/dapi/v1/exchangeInfo",
+                Dapi::Depth => "/dapi/v1/depth",
+                Dapi::Klines => "/dapi/v1/klines",
+                Dapi::PremiumIndex => "/dapi/v1/premiumIndex",
+                Dapi::TickerPrice => "/dapi/v1/ticker/price",
+                Dapi::Order => "/dapi/v1/order",
+                Dapi::OpenOrders => "/dapi/v1/openOrders",
+                Dapi::AllOpenOrders => "/dapi/v1/allOpenOrders",
+                Dapi::CountdownCancelAll => "/dapi/v1/countdownCancelAll",
+                Dapi::Account => "/dapi/v1/account",
+                Dapi::Balance => "/dapi/v1/balance",
+                Dapi::PositionRisk => "/dapi/v1/positionRisk",
+                Dapi::ChangeInitialLeverage => "/dapi/v1/leverage",
+                Dapi::UserDataStream => "/dapi/v1/listenKey",
+            },
+            API::Options(route) => match route {
+                Eapi::ExchangeInfo => "/eapi/v1/exchangeInfo",
+                Eapi::Index => "/eapi/v1/index",
+                Eapi::Mark => "/eapi/v1/mark",
+                Eapi::Depth => "/eapi/v1/depth",
+                Eapi::Klines => "/eapi/v1/klines",
+                Eapi::Trades => "/eapi/v1/trades",
+                Eapi::OpenInterest => "/eapi/v1/openInterest",
+                Eapi::ExerciseHistory => "/eapi/v1/exerciseHistory",
+                Eapi::Order => "/eapi/v1/order",
+                Eapi::BatchOrders => "/eapi/v1/batchOrders",
+                Eapi::Position => "/eapi/v1/position",
+                Eapi::UserTrades => "/eapi/v1/userTrades",
+                Eapi::MarginAccount => "/eapi/v1/marginAccount",
+                Eapi::CountdownCancelAll => "/eapi/v1/countdownCancelAll",
+                Eapi::CountdownCancelAllHeartBeat => "/eapi/v1/countdownCancelAllHeartBeat",
+                Eapi::UserDataStream => "/eapi/v1/listenKey",
+            },
         })
     }
 }
@@ -213,6 +491,66 @@ impl Binance for Savings {
     }
 }
 
+impl Binance for SubAccount {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Self {
+        Self {
+            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone()),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+impl Binance for SimpleEarn {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Self {
+        Self {
+            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone()),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+impl Binance for Convert {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Self {
+        Self {
+            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone()),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+impl Binance for Margin {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Self {
+        Self {
+            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone()),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
 impl Binance for Market {
     fn new(api_key: Option<String>, secret_key: Option<String>) -> Market {
         Self::new_with_config(api_key, secret_key, &Config::default())
@@ -321,3 +659,109 @@ impl Binance for FuturesUserStream {
         }
     }
 }
+
+impl Binance for CoinMMarket {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Self {
+        Self {
+            client: Client::new(api_key, secret_key, config.coin_m_rest_api_endpoint.clone()),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+impl Binance for CoinMAccount {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Self {
+        Self {
+            client: Client::new(api_key, secret_key, config.coin_m_rest_api_endpoint.clone()),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+impl Binance for CoinMUserStream {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Self {
+        Self {
+            client: Client::new(api_key, secret_key, config.coin_m_rest_api_endpoint.clone()),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+// *****************************************************
+//              Binance Options API
+// *****************************************************
+
+impl Binance for OptionsMarket {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Self {
+        Self {
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.options_rest_api_endpoint.clone(),
+            ),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+impl Binance for OptionsAccount {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Self {
+        Self {
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.options_rest_api_endpoint.clone(),
+            ),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+impl Binance for OptionsUserStream {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Self {
+        Self {
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.options_rest_api_endpoint.clone(),
+            ),
+            recv_window: config.recv_window,
+        }
+    }
+}
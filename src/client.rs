@@ -53,6 +53,17 @@ impl Client {
         self.handler(response)
     }
 
+    pub fn put_signed<T: DeserializeOwned>(&self, endpoint: API, request: String) -> Result<T> {
+        let url = self.sign_request(endpoint, Some(request));
+        let client = &self.inner_client;
+        let response = client
+            .put(url.as_str())
+            .headers(self.build_headers(true)?)
+            .send()?;
+
+        self.handler(response)
+    }
+
     pub fn delete_signed<T: DeserializeOwned>(
         &self, endpoint: API, request: Option<String>,
     ) -> Result<T> {
@@ -80,6 +91,69 @@ impl Client {
         self.handler(response)
     }
 
+    // Request is not signed, but the API key must be sent in the header
+    pub fn get_with_api_key<T: DeserializeOwned>(
+        &self, endpoint: API, request: Option<String>,
+    ) -> Result<T> {
+        let mut url: String = format!("{}{}", self.host, String::from(endpoint));
+        if let Some(request) = request {
+            if !request.is_empty() {
+                url.push_str(format!("?{}", request).as_str());
+            }
+        }
+
+        let client = &self.inner_client;
+        let response = client
+            .get(url.as_str())
+            .headers(self.build_headers(false)?)
+            .send()?;
+
+        self.handler(response)
+    }
+
+    // Requests below are not signed either, the parameters are sent in the query string
+    pub fn post_with_api_key<T: DeserializeOwned>(
+        &self, endpoint: API, request: String,
+    ) -> Result<T> {
+        let url: String = format!("{}{}?{}", self.host, String::from(endpoint), request);
+
+        let client = &self.inner_client;
+        let response = client
+            .post(url.as_str())
+            .headers(self.build_headers(false)?)
+            .send()?;
+
+        self.handler(response)
+    }
+
+    pub fn put_with_api_key<T: DeserializeOwned>(
+        &self, endpoint: API, request: String,
+    ) -> Result<T> {
+        let url: String = format!("{}{}?{}", self.host, String::from(endpoint), request);
+
+        let client = &self.inner_client;
+        let response = client
+            .put(url.as_str())
+            .headers(self.build_headers(false)?)
+            .send()?;
+
+        self.handler(response)
+    }
+
+    pub fn delete_with_api_key<T: DeserializeOwned>(
+        &self, endpoint: API, request: String,
+    ) -> Result<T> {
+        let url: String = format!("{}{}?{}", self.host, String::from(endpoint), request);
+
+        let client = &self.inner_client;
+        let response = client
+            .delete(url.as_str())
+            .headers(self.build_headers(false)?)
+            .send()?;
+
+        self.handler(response)
+    }
+
     pub fn post<T: DeserializeOwned>(&self, endpoint: API) -> Result<T> {
         let url: String = format!("{}{}", self.host, String::from(endpoint));
 
@@ -169,7 +243,11 @@ impl Client {
                 bail!("Service Unavailable");
             }
             StatusCode::UNAUTHORIZED => {
-                bail!("Unauthorized");
+                // Rejected keys come with an error code, e.g. -2015
+                match response.json::<BinanceContentError>() {
+                    Ok(error) => Err(ErrorKind::BinanceError(error).into()),
+                    Err(_) => bail!("Unauthorized"),
+                }
             }
             StatusCode::BAD_REQUEST => {
                 let error: BinanceContentError = response.json()?;
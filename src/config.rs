@@ -6,10 +6,17 @@ pub struct Config {
     pub futures_rest_api_endpoint: String,
     pub futures_ws_endpoint: String,
 
+    pub coin_m_rest_api_endpoint: String,
+    pub coin_m_ws_endpoint: String,
+
+    pub options_rest_api_endpoint: String,
+    pub options_ws_endpoint: String,
+
     pub recv_window: u64,
 }
 
 impl Config {
+    #[allow(clippy::should_implement_trait)]
     pub fn default() -> Config {
         Config {
             rest_api_endpoint: "https://api.binance.com".into(),
@@ -18,6 +25,12 @@ impl Config {
             futures_rest_api_endpoint: "https://fapi.binance.com".into(),
             futures_ws_endpoint: "wss://fstream.binance.com/ws".into(),
 
+            coin_m_rest_api_endpoint: "https://dapi.binance.com".into(),
+            coin_m_ws_endpoint: "wss://dstream.binance.com/ws".into(),
+
+            options_rest_api_endpoint: "https://eapi.binance.com".into(),
+            options_ws_endpoint: "wss://vstream.binance.com/ws".into(),
+
             recv_window: 5000,
         }
     }
@@ -27,7 +40,11 @@ impl Config {
             .set_rest_api_endpoint("https://testnet.binance.vision")
             .set_ws_endpoint("wss://testnet.binance.vision/ws")
             .set_futures_rest_api_endpoint("https://testnet.binancefuture.com")
-            .set_futures_ws_endpoint("https://testnet.binancefuture.com/ws")
+            .set_futures_ws_endpoint("wss://stream.binancefuture.com/ws")
+            .set_coin_m_rest_api_endpoint("https://testnet.binancefuture.com")
+            .set_coin_m_ws_endpoint("wss://dstream.binancefuture.com/ws")
+            .set_options_rest_api_endpoint("https://testnet.binanceops.com")
+            .set_options_ws_endpoint("wss://testnetws.binanceops.com/ws")
     }
 
     pub fn set_rest_api_endpoint<T: Into<String>>(mut self, rest_api_endpoint: T) -> Self {
@@ -51,6 +68,30 @@ impl Config {
         self
     }
 
+    pub fn set_coin_m_rest_api_endpoint<T: Into<String>>(
+        mut self, coin_m_rest_api_endpoint: T,
+    ) -> Self {
+        self.coin_m_rest_api_endpoint = coin_m_rest_api_endpoint.into();
+        self
+    }
+
+    pub fn set_coin_m_ws_endpoint<T: Into<String>>(mut self, coin_m_ws_endpoint: T) -> Self {
+        self.coin_m_ws_endpoint = coin_m_ws_endpoint.into();
+        self
+    }
+
+    pub fn set_options_rest_api_endpoint<T: Into<String>>(
+        mut self, options_rest_api_endpoint: T,
+    ) -> Self {
+        self.options_rest_api_endpoint = options_rest_api_endpoint.into();
+        self
+    }
+
+    pub fn set_options_ws_endpoint<T: Into<String>>(mut self, options_ws_endpoint: T) -> Self {
+        self.options_ws_endpoint = options_ws_endpoint.into();
+        self
+    }
+
     pub fn set_recv_window(mut self, recv_window: u64) -> Self {
         self.recv_window = recv_window;
         self
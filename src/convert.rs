@@ -0,0 +1,198 @@
+use crate::util::*;
+use crate::model::*;
+use crate::client::*;
+use crate::errors::*;
+use crate::preflight::{ApiPermission, RequiredPermissions};
+use crate::market::Market;
+use std::collections::BTreeMap;
+use crate::api::API;
+use crate::api::Sapi;
+
+#[derive(Clone)]
+pub struct Convert {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl RequiredPermissions for Convert {
+    fn required_permissions() -> Vec<ApiPermission> {
+        vec![ApiPermission::Reading, ApiPermission::SpotAndMarginTrading]
+    }
+}
+
+/// Amount of a quote, either in the asset sold or in the asset bought.
+pub enum ConvertAmount {
+    From(f64),
+    To(f64),
+}
+
+pub enum ConvertWallet {
+    Spot,
+    Funding,
+}
+
+impl From<ConvertWallet> for String {
+    fn from(item: ConvertWallet) -> Self {
+        match item {
+            ConvertWallet::Spot => String::from("SPOT"),
+            ConvertWallet::Funding => String::from("FUNDING"),
+        }
+    }
+}
+
+/// Time a quote can be accepted for, 10 seconds by default.
+pub enum QuoteValidTime {
+    TenSeconds,
+    ThirtySeconds,
+    OneMinute,
+    TwoMinutes,
+}
+
+impl From<QuoteValidTime> for String {
+    fn from(item: QuoteValidTime) -> Self {
+        match item {
+            QuoteValidTime::TenSeconds => String::from("10s"),
+            QuoteValidTime::ThirtySeconds => String::from("30s"),
+            QuoteValidTime::OneMinute => String::from("1m"),
+            QuoteValidTime::TwoMinutes => String::from("2m"),
+        }
+    }
+}
+
+impl Convert {
+    /// Convertible pairs, optionally filtered by from_asset and/or to_asset.
+    pub fn get_exchange_info(
+        &self, from_asset: Option<String>, to_asset: Option<String>,
+    ) -> Result<Vec<ConvertPair>> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(from_asset) = from_asset {
+            parameters.insert("fromAsset".into(), from_asset);
+        }
+        if let Some(to_asset) = to_asset {
+            parameters.insert("toAsset".into(), to_asset);
+        }
+
+        let request = build_request(parameters);
+        self.client
+            .get(API::Savings(Sapi::ConvertExchangeInfo), Some(request))
+    }
+
+    /// Request a quote to convert from_asset to to_asset.
+    pub fn get_quote<S1, S2>(
+        &self, from_asset: S1, to_asset: S2, amount: ConvertAmount,
+        wallet_type: Option<ConvertWallet>, valid_time: Option<QuoteValidTime>,
+    ) -> Result<ConvertQuote>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("fromAsset".into(), from_asset.into());
+        parameters.insert("toAsset".into(), to_asset.into());
+        match amount {
+            ConvertAmount::From(amount) => {
+                parameters.insert("fromAmount".into(), amount.to_string())
+            }
+            ConvertAmount::To(amount) => parameters.insert("toAmount".into(), amount.to_string()),
+        };
+        if let Some(wallet_type) = wallet_type {
+            parameters.insert("walletType".into(), wallet_type.into());
+        }
+        if let Some(valid_time) = valid_time {
+            parameters.insert("validTime".into(), valid_time.into());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::ConvertGetQuote), request)
+    }
+
+    /// Accept a quote before its valid_timestamp.
+    pub fn accept_quote<S>(&self, quote_id: S) -> Result<ConvertAcceptance>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("quoteId".into(), quote_id.into());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::ConvertAcceptQuote), request)
+    }
+
+    // Status of the order created by accepting a quote
+    pub fn get_order_status(&self, order_id: u64) -> Result<ConvertOrder> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("orderId".into(), order_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::ConvertOrderStatus), Some(request))
+    }
+
+    /// Convert trades between start_time and end_time (max 30 days apart).
+    pub fn get_trade_flow<S>(
+        &self, start_time: u64, end_time: u64, limit: S,
+    ) -> Result<ConvertTradeFlow>
+    where
+        S: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("startTime".into(), start_time.to_string());
+        parameters.insert("endTime".into(), end_time.to_string());
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), lt.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::ConvertTradeFlow), Some(request))
+    }
+
+    /// Request a quote and accept it only if its ratio is at most `tolerance` (e.g. 0.005 for
+    /// 0.5%) worse than the spot market price.
+    ///
+    /// The market price comes from `Market::get_price` on FROMTO, or on TOFROM if FROMTO is
+    /// not listed. A quote that is not accepted simply expires.
+    pub fn convert_within_tolerance<S1, S2>(
+        &self, market: &Market, from_asset: S1, to_asset: S2, amount: ConvertAmount, tolerance: f64,
+    ) -> Result<ConvertAcceptance>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let from_asset = from_asset.into();
+        let to_asset = to_asset.into();
+
+        let market_ratio = market_ratio(market, &from_asset, &to_asset)?;
+        let quote = self.get_quote(from_asset, to_asset, amount, None, None)?;
+        let min_ratio = market_ratio * (1.0 - tolerance);
+        if quote.ratio < min_ratio {
+            bail!(format!(
+                "Quote {} rejected: ratio {} is below {} (market ratio {})",
+                quote.quote_id, quote.ratio, min_ratio, market_ratio
+            ));
+        }
+
+        self.accept_quote(quote.quote_id)
+    }
+}
+
+// Error code of an unknown symbol
+const INVALID_SYMBOL: i16 = -1121;
+
+// Amount of to_asset received for one from_asset at the spot market price
+fn market_ratio(market: &Market, from_asset: &str, to_asset: &str) -> Result<f64> {
+    match market.get_price(format!("{}{}", from_asset, to_asset)) {
+        Ok(price) => Ok(price.price),
+        // Only listed the other way around
+        Err(Error(ErrorKind::BinanceError(ref response), _)) if response.code == INVALID_SYMBOL => {
+            let price = market.get_price(format!("{}{}", to_asset, from_asset))?;
+            if price.price <= 0.0 {
+                bail!(format!("Invalid price for {}", price.symbol));
+            }
+            Ok(1.0 / price.price)
+        }
+        Err(e) => Err(e),
+    }
+}
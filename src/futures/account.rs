@@ -2,21 +2,41 @@ use std::collections::BTreeMap;
 
 use crate::util::*;
 use crate::errors::*;
+use crate::preflight::{ApiPermission, RequiredPermissions};
+use serde::Deserialize;
 use crate::client::Client;
 use crate::api::{API, Futures};
-use crate::model::Empty;
+use crate::model::{BatchResult, Empty};
 use crate::account::{OrderSide, TimeInForce};
 use super::model::{
     ChangeLeverageResponse, Transaction, CanceledOrder, PositionRisk, AccountBalance,
-    AccountInformation,
+    AccountInformation, AdlQuantile, CommissionRate, CountdownCancelAll, DownloadId, DownloadLink,
+    Income, IncomeType, LeverageBracket, MultiAssetsMargin, Order, OrderAmendment,
+    PositionMarginChange, PositionMarginResponse, PositionMode, UserTrade,
 };
 
+/// Largest number of orders of a batch.
+pub const FUTURES_BATCH_ORDERS_LIMIT: usize = 5;
+/// Largest number of orders canceled by one batch cancel.
+pub const FUTURES_BATCH_CANCEL_LIMIT: usize = 10;
+
+/// Largest time range of one order or trade history request, 7 days.
+pub const HISTORY_WINDOW_MS: u64 = 7 * 24 * 60 * 60 * 1000;
+/// Largest page returned by the order, trade and income history requests.
+pub const HISTORY_PAGE_LIMIT: u16 = 1000;
+
 #[derive(Clone)]
 pub struct FuturesAccount {
     pub client: Client,
     pub recv_window: u64,
 }
 
+impl RequiredPermissions for FuturesAccount {
+    fn required_permissions() -> Vec<ApiPermission> {
+        vec![ApiPermission::Reading, ApiPermission::Futures]
+    }
+}
+
 pub enum ContractType {
     Perpetual,
     CurrentMonth,
@@ -53,6 +73,34 @@ impl From<PositionSide> for String {
     }
 }
 
+pub enum MarginType {
+    Isolated,
+    Crossed,
+}
+
+impl From<MarginType> for String {
+    fn from(item: MarginType) -> Self {
+        match item {
+            MarginType::Isolated => String::from("ISOLATED"),
+            MarginType::Crossed => String::from("CROSSED"),
+        }
+    }
+}
+
+pub enum PositionMarginAction {
+    Add,
+    Reduce,
+}
+
+impl From<PositionMarginAction> for String {
+    fn from(item: PositionMarginAction) -> Self {
+        match item {
+            PositionMarginAction::Add => String::from("1"),
+            PositionMarginAction::Reduce => String::from("2"),
+        }
+    }
+}
+
 pub enum OrderType {
     Limit,
     Market,
@@ -91,6 +139,48 @@ impl From<WorkingType> for String {
     }
 }
 
+/// Price of an order taken from the order book instead of given, e.g. `Queue5` is the
+/// fifth best price on the side of the order.
+pub enum PriceMatch {
+    Opponent,
+    Opponent5,
+    Opponent10,
+    Opponent20,
+    Queue,
+    Queue5,
+    Queue10,
+    Queue20,
+}
+
+impl From<PriceMatch> for String {
+    fn from(item: PriceMatch) -> Self {
+        match item {
+            PriceMatch::Opponent => String::from("OPPONENT"),
+            PriceMatch::Opponent5 => String::from("OPPONENT_5"),
+            PriceMatch::Opponent10 => String::from("OPPONENT_10"),
+            PriceMatch::Opponent20 => String::from("OPPONENT_20"),
+            PriceMatch::Queue => String::from("QUEUE"),
+            PriceMatch::Queue5 => String::from("QUEUE_5"),
+            PriceMatch::Queue10 => String::from("QUEUE_10"),
+            PriceMatch::Queue20 => String::from("QUEUE_20"),
+        }
+    }
+}
+
+pub enum AutoCloseType {
+    Liquidation,
+    Adl,
+}
+
+impl From<AutoCloseType> for String {
+    fn from(item: AutoCloseType) -> Self {
+        match item {
+            AutoCloseType::Liquidation => String::from("LIQUIDATION"),
+            AutoCloseType::Adl => String::from("ADL"),
+        }
+    }
+}
+
 struct OrderRequest {
     pub symbol: String,
     pub side: OrderSide,
@@ -125,6 +215,88 @@ pub struct CustomOrderRequest {
     pub price_protect: Option<f64>,
 }
 
+/// New price and quantity of a LIMIT order, the order keeps its id.
+///
+/// The order is given by `order_id` or `orig_client_order_id`, and the price by `price`
+/// or `price_match`.
+pub struct ModifyOrderRequest {
+    pub symbol: String,
+    pub order_id: Option<u64>,
+    pub orig_client_order_id: Option<String>,
+    pub side: OrderSide,
+    pub qty: f64,
+    pub price: Option<f64>,
+    pub price_match: Option<PriceMatch>,
+}
+
+impl ModifyOrderRequest {
+    pub fn new<S>(symbol: S, order_id: u64, side: OrderSide, qty: f64, price: f64) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            symbol: symbol.into(),
+            order_id: Some(order_id),
+            orig_client_order_id: None,
+            side,
+            qty,
+            price: Some(price),
+            price_match: None,
+        }
+    }
+
+    pub fn with_price_match<S>(
+        symbol: S, order_id: u64, side: OrderSide, qty: f64, price_match: PriceMatch,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            price: None,
+            price_match: Some(price_match),
+            ..Self::new(symbol, order_id, side, qty, 0.0)
+        }
+    }
+}
+
+impl From<CustomOrderRequest> for OrderRequest {
+    fn from(order_request: CustomOrderRequest) -> Self {
+        Self {
+            symbol: order_request.symbol,
+            side: order_request.side,
+            position_side: order_request.position_side,
+            order_type: order_request.order_type,
+            time_in_force: order_request.time_in_force,
+            qty: order_request.qty,
+            reduce_only: order_request.reduce_only,
+            price: order_request.price,
+            stop_price: order_request.stop_price,
+            close_position: order_request.close_position,
+            activation_price: order_request.activation_price,
+            callback_rate: order_request.callback_rate,
+            working_type: order_request.working_type,
+            price_protect: order_request.price_protect,
+        }
+    }
+}
+
+// Response that is an object when a symbol is given and a list otherwise
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OneOrMany<T> {
+    One(T),
+    Many(Vec<T>),
+}
+
+impl<T> OneOrMany<T> {
+    fn into_vec(self) -> Vec<T> {
+        match self {
+            OneOrMany::One(item) => vec![item],
+            OneOrMany::Many(items) => items,
+        }
+    }
+}
+
 impl FuturesAccount {
     pub fn limit_buy(
         &self, symbol: impl Into<String>, qty: impl Into<f64>, price: f64,
@@ -318,28 +490,138 @@ impl FuturesAccount {
 
     // Custom order for for professional traders
     pub fn custom_order(&self, order_request: CustomOrderRequest) -> Result<Transaction> {
-        let order: OrderRequest = OrderRequest {
-            symbol: order_request.symbol,
-            side: order_request.side,
-            position_side: order_request.position_side,
-            order_type: order_request.order_type,
-            time_in_force: order_request.time_in_force,
-            qty: order_request.qty,
-            reduce_only: order_request.reduce_only,
-            price: order_request.price,
-            stop_price: order_request.stop_price,
-            close_position: order_request.close_position,
-            activation_price: order_request.activation_price,
-            callback_rate: order_request.callback_rate,
-            working_type: order_request.working_type,
-            price_protect: order_request.price_protect,
-        };
-        let order = self.build_order(order);
+        let order = self.build_order(order_request.into());
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed(API::Futures(Futures::Order), request)
     }
 
+    /// Places up to `FUTURES_BATCH_ORDERS_LIMIT` custom orders in one request, each one
+    /// succeeds or fails on its own.
+    pub fn custom_batch_orders(
+        &self, order_requests: Vec<CustomOrderRequest>,
+    ) -> Result<Vec<std::result::Result<Transaction, BinanceContentError>>> {
+        if order_requests.is_empty() || order_requests.len() > FUTURES_BATCH_ORDERS_LIMIT {
+            bail!(format!(
+                "A batch must have between 1 and {} orders, {} given",
+                FUTURES_BATCH_ORDERS_LIMIT,
+                order_requests.len()
+            ));
+        }
+        let orders: Vec<BTreeMap<String, String>> = order_requests
+            .into_iter()
+            .map(|order_request| self.build_order(order_request.into()))
+            .collect();
+
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("batchOrders".into(), build_json_list_param(&orders)?);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        let results: Vec<BatchResult<Transaction>> = self
+            .client
+            .post_signed(API::Futures(Futures::BatchOrders), request)?;
+        Ok(results.into_iter().map(BatchResult::into_result).collect())
+    }
+
+    pub fn modify_order(&self, modify_request: ModifyOrderRequest) -> Result<Transaction> {
+        let order = Self::build_modify_order(modify_request)?;
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .put_signed(API::Futures(Futures::Order), request)
+    }
+
+    /// Modifies up to `FUTURES_BATCH_ORDERS_LIMIT` orders in one request, each one succeeds
+    /// or fails on its own.
+    pub fn modify_batch_orders(
+        &self, modify_requests: Vec<ModifyOrderRequest>,
+    ) -> Result<Vec<std::result::Result<Transaction, BinanceContentError>>> {
+        if modify_requests.is_empty() || modify_requests.len() > FUTURES_BATCH_ORDERS_LIMIT {
+            bail!(format!(
+                "A batch must have between 1 and {} orders, {} given",
+                FUTURES_BATCH_ORDERS_LIMIT,
+                modify_requests.len()
+            ));
+        }
+        let orders = modify_requests
+            .into_iter()
+            .map(Self::build_modify_order)
+            .collect::<Result<Vec<BTreeMap<String, String>>>>()?;
+
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("batchOrders".into(), build_json_list_param(&orders)?);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        let results: Vec<BatchResult<Transaction>> = self
+            .client
+            .put_signed(API::Futures(Futures::BatchOrders), request)?;
+        Ok(results.into_iter().map(BatchResult::into_result).collect())
+    }
+
+    // Modifications of the orders of a symbol, or of one order (max 100)
+    pub fn get_order_amendments<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, order_id: S2, start_time: S3, end_time: S4, limit: S5,
+    ) -> Result<Vec<OrderAmendment>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(oi) = order_id.into() {
+            parameters.insert("orderId".into(), oi.to_string());
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), st.to_string());
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), et.to_string());
+        }
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), lt.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::OrderAmendment), Some(request))
+    }
+
+    /// Cancels up to `FUTURES_BATCH_CANCEL_LIMIT` orders of a symbol, by order id or by
+    /// client order id, each one succeeds or fails on its own.
+    pub fn cancel_batch_orders<S>(
+        &self, symbol: S, order_ids: Vec<u64>, orig_client_order_ids: Vec<String>,
+    ) -> Result<Vec<std::result::Result<CanceledOrder, BinanceContentError>>>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+
+        match (order_ids.len(), orig_client_order_ids.len()) {
+            (1..=FUTURES_BATCH_CANCEL_LIMIT, 0) => {
+                parameters.insert("orderIdList".into(), build_json_list_param(&order_ids)?);
+            }
+            (0, 1..=FUTURES_BATCH_CANCEL_LIMIT) => {
+                parameters.insert(
+                    "origClientOrderIdList".into(),
+                    build_json_list_param(&orig_client_order_ids)?,
+                );
+            }
+            (ids, client_ids) => bail!(format!(
+                "A batch cancel must have between 1 and {} order ids or client order ids, {} and {} given",
+                FUTURES_BATCH_CANCEL_LIMIT, ids, client_ids
+            )),
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        let results: Vec<BatchResult<CanceledOrder>> = self
+            .client
+            .delete_signed(API::Futures(Futures::BatchOrders), Some(request))?;
+        Ok(results.into_iter().map(BatchResult::into_result).collect())
+    }
+
     fn build_order(&self, order: OrderRequest) -> BTreeMap<String, String> {
         let mut parameters = BTreeMap::new();
         parameters.insert("symbol".into(), order.symbol);
@@ -389,6 +671,34 @@ impl FuturesAccount {
         parameters
     }
 
+    fn build_modify_order(order: ModifyOrderRequest) -> Result<BTreeMap<String, String>> {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), order.symbol);
+        parameters.insert("side".into(), order.side.into());
+        parameters.insert("quantity".into(), order.qty.to_string());
+
+        match (order.order_id, order.orig_client_order_id) {
+            (Some(order_id), _) => {
+                parameters.insert("orderId".into(), order_id.to_string());
+            }
+            (None, Some(orig_client_order_id)) => {
+                parameters.insert("origClientOrderId".into(), orig_client_order_id);
+            }
+            (None, None) => bail!("Either an order id or a client order id must be given"),
+        }
+        match (order.price, order.price_match) {
+            (Some(price), None) => {
+                parameters.insert("price".into(), price.to_string());
+            }
+            (None, Some(price_match)) => {
+                parameters.insert("priceMatch".into(), price_match.into());
+            }
+            _ => bail!("Exactly one of price and price match must be given"),
+        }
+
+        Ok(parameters)
+    }
+
     pub fn position_information<S>(&self, symbol: S) -> Result<Vec<PositionRisk>>
     where
         S: Into<String>,
@@ -443,6 +753,213 @@ impl FuturesAccount {
             .map(|_| ())
     }
 
+    pub fn get_position_mode(&self) -> Result<PositionMode> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::PositionSide), Some(request))
+    }
+
+    pub fn change_margin_type<S>(&self, symbol: S, margin_type: MarginType) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("marginType".into(), margin_type.into());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Futures(Futures::MarginType), request)
+            .map(|_| ())
+    }
+
+    // Adds margin to, or removes margin from, an isolated position
+    pub fn modify_position_margin<S>(
+        &self, symbol: S, amount: f64, action: PositionMarginAction,
+        position_side: Option<PositionSide>,
+    ) -> Result<PositionMarginResponse>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("amount".into(), amount.to_string());
+        parameters.insert("type".into(), action.into());
+        if let Some(position_side) = position_side {
+            parameters.insert("positionSide".into(), position_side.into());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Futures(Futures::PositionMargin), request)
+    }
+
+    pub fn get_position_margin_history<S1, S2, S3, S4>(
+        &self, symbol: S1, action: Option<PositionMarginAction>, start_time: S2, end_time: S3,
+        limit: S4,
+    ) -> Result<Vec<PositionMarginChange>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(action) = action {
+            parameters.insert("type".into(), action.into());
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), st.to_string());
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), et.to_string());
+        }
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), lt.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::PositionMarginHistory), Some(request))
+    }
+
+    pub fn get_multi_assets_margin(&self) -> Result<MultiAssetsMargin> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::MultiAssetsMargin), Some(request))
+    }
+
+    pub fn change_multi_assets_margin(&self, multi_assets_margin: bool) -> Result<()> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("multiAssetsMargin".into(), multi_assets_margin.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Futures(Futures::MultiAssetsMargin), request)
+            .map(|_| ())
+    }
+
+    // Changes of the balance, of all symbols and types if none are given (max 1000)
+    pub fn get_income<S1, S2, S3, S4>(
+        &self, symbol: Option<String>, income_type: Option<IncomeType>, start_time: S1,
+        end_time: S2, page: S3, limit: S4,
+    ) -> Result<Vec<Income>>
+    where
+        S1: Into<Option<u64>>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u32>>,
+        S4: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(symbol) = symbol {
+            parameters.insert("symbol".into(), symbol);
+        }
+        match income_type {
+            // Stands for the types this crate doesn't know of, it can't be requested
+            Some(IncomeType::Unknown) => bail!("IncomeType::Unknown can't be used as a filter"),
+            Some(income_type) => {
+                parameters.insert("incomeType".into(), income_type.into());
+            }
+            None => {}
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), st.to_string());
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), et.to_string());
+        }
+        if let Some(pg) = page.into() {
+            parameters.insert("page".into(), pg.to_string());
+        }
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), lt.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::Income), Some(request))
+    }
+
+    /// Changes of the balance between start_time and end_time, read page by page.
+    pub fn income_range(
+        &self, symbol: Option<String>, income_type: Option<IncomeType>, start_time: u64,
+        end_time: u64,
+    ) -> IncomeHistory<'_> {
+        Pages::new(IncomePages {
+            account: self,
+            symbol,
+            income_type,
+            start_time,
+            end_time,
+            page: 1,
+            finished: start_time > end_time,
+        })
+    }
+
+    // Asks for a file of the income history, see get_income_download_link
+    pub fn get_income_download_id(&self, start_time: u64, end_time: u64) -> Result<DownloadId> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("startTime".into(), start_time.to_string());
+        parameters.insert("endTime".into(), end_time.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::IncomeDownloadId), Some(request))
+    }
+
+    pub fn get_income_download_link<S>(&self, download_id: S) -> Result<DownloadLink>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("downloadId".into(), download_id.into());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::IncomeDownloadLink), Some(request))
+    }
+
+    // Notional tiers of a symbol, or of all symbols
+    pub fn get_leverage_brackets(&self, symbol: Option<String>) -> Result<Vec<LeverageBracket>> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(symbol) = symbol {
+            parameters.insert("symbol".into(), symbol);
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        let brackets: OneOrMany<LeverageBracket> = self
+            .client
+            .get_signed(API::Futures(Futures::LeverageBracket), Some(request))?;
+        Ok(brackets.into_vec())
+    }
+
+    // ADL queue positions of a symbol, or of all symbols with a position
+    pub fn get_adl_quantiles(&self, symbol: Option<String>) -> Result<Vec<AdlQuantile>> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(symbol) = symbol {
+            parameters.insert("symbol".into(), symbol);
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        let quantiles: OneOrMany<AdlQuantile> = self
+            .client
+            .get_signed(API::Futures(Futures::AdlQuantile), Some(request))?;
+        Ok(quantiles.into_vec())
+    }
+
+    pub fn get_commission_rate<S>(&self, symbol: S) -> Result<CommissionRate>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::CommissionRate), Some(request))
+    }
+
     pub fn cancel_all_open_orders<S>(&self, symbol: S) -> Result<()>
     where
         S: Into<String>,
@@ -455,6 +972,23 @@ impl FuturesAccount {
             .map(|_| ())
     }
 
+    /// Cancels all open orders of the symbol once countdown_time milliseconds have passed
+    /// without another call, 0 disables the countdown.
+    pub fn countdown_cancel_all<S>(
+        &self, symbol: S, countdown_time: u64,
+    ) -> Result<CountdownCancelAll>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("countdownTime".into(), countdown_time.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Futures(Futures::CountdownCancelAll), request)
+    }
+
     pub fn get_all_open_orders<S>(&self, symbol: S) -> Result<Vec<crate::futures::model::Order>>
     where
         S: Into<String>,
@@ -465,4 +999,374 @@ impl FuturesAccount {
         self.client
             .get_signed(API::Futures(Futures::OpenOrders), Some(request))
     }
+
+    pub fn get_order<S>(&self, symbol: S, order_id: u64) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::Order), Some(request))
+    }
+
+    pub fn get_order_with_client_id<S>(
+        &self, symbol: S, orig_client_order_id: String,
+    ) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("origClientOrderId".into(), orig_client_order_id);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::Order), Some(request))
+    }
+
+    // Fails if the order is no longer open
+    pub fn get_open_order<S>(&self, symbol: S, order_id: u64) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::OpenOrder), Some(request))
+    }
+
+    pub fn get_open_order_with_client_id<S>(
+        &self, symbol: S, orig_client_order_id: String,
+    ) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("origClientOrderId".into(), orig_client_order_id);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::OpenOrder), Some(request))
+    }
+
+    // Orders of a symbol from order_id on, or in a range of at most 7 days (max 1000)
+    pub fn get_all_orders<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, order_id: S2, start_time: S3, end_time: S4, limit: S5,
+    ) -> Result<Vec<Order>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(oi) = order_id.into() {
+            parameters.insert("orderId".into(), oi.to_string());
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), st.to_string());
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), et.to_string());
+        }
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), lt.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::AllOrders), Some(request))
+    }
+
+    // Fills of a symbol from from_id on, or in a range of at most 7 days (max 1000)
+    pub fn get_user_trades<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, from_id: S2, start_time: S3, end_time: S4, limit: S5,
+    ) -> Result<Vec<UserTrade>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(fi) = from_id.into() {
+            parameters.insert("fromId".into(), fi.to_string());
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), st.to_string());
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), et.to_string());
+        }
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), lt.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::UserTrades), Some(request))
+    }
+
+    // Liquidation and ADL orders of the account, of all symbols if none is given (max 100)
+    pub fn get_force_orders<S1, S2, S3>(
+        &self, symbol: Option<String>, auto_close_type: Option<AutoCloseType>, start_time: S1,
+        end_time: S2, limit: S3,
+    ) -> Result<Vec<Order>>
+    where
+        S1: Into<Option<u64>>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(symbol) = symbol {
+            parameters.insert("symbol".into(), symbol);
+        }
+        if let Some(auto_close_type) = auto_close_type {
+            parameters.insert("autoCloseType".into(), auto_close_type.into());
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), st.to_string());
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), et.to_string());
+        }
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), lt.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::ForceOrders), Some(request))
+    }
+
+    /// Orders of a symbol created between start_time and end_time, read 7 days at a time.
+    pub fn all_orders_range<S>(
+        &self, symbol: S, start_time: u64, end_time: u64,
+    ) -> AccountHistory<'_, Order>
+    where
+        S: Into<String>,
+    {
+        Pages::new(AccountHistoryPages::new(
+            self,
+            symbol.into(),
+            start_time,
+            end_time,
+            |account, symbol, from_id, start, end| {
+                account.get_all_orders(symbol, from_id, start, end, HISTORY_PAGE_LIMIT)
+            },
+        ))
+    }
+
+    /// Fills of a symbol between start_time and end_time, read 7 days at a time.
+    pub fn user_trades_range<S>(
+        &self, symbol: S, start_time: u64, end_time: u64,
+    ) -> AccountHistory<'_, UserTrade>
+    where
+        S: Into<String>,
+    {
+        Pages::new(AccountHistoryPages::new(
+            self,
+            symbol.into(),
+            start_time,
+            end_time,
+            |account, symbol, from_id, start, end| {
+                account.get_user_trades(symbol, from_id, start, end, HISTORY_PAGE_LIMIT)
+            },
+        ))
+    }
+}
+
+/// Record of a history of the account, ordered by time and then by id.
+pub trait HistoryRecord {
+    fn id(&self) -> u64;
+    fn time(&self) -> Option<u64>;
+}
+
+impl HistoryRecord for Order {
+    fn id(&self) -> u64 {
+        self.order_id
+    }
+
+    fn time(&self) -> Option<u64> {
+        self.time
+    }
+}
+
+impl HistoryRecord for UserTrade {
+    fn id(&self) -> u64 {
+        self.id
+    }
+
+    fn time(&self) -> Option<u64> {
+        Some(self.time)
+    }
+}
+
+// Reads a page from an id (`orderId` or `fromId`), or in a time range
+type HistoryPage<T> =
+    fn(&FuturesAccount, &str, Option<u64>, Option<u64>, Option<u64>) -> Result<Vec<T>>;
+
+/// Iterator over the orders or fills of a symbol in a time range.
+pub type AccountHistory<'a, T> = Pages<AccountHistoryPages<'a, T>>;
+
+/// Pages of an `AccountHistory`.
+///
+/// The range is requested in windows of `HISTORY_WINDOW_MS`. A full page is followed by a
+/// request starting at the time of its last record, records already returned are skipped.
+/// When a full page doesn't move the time forward, the following pages are read by id until
+/// a record past that time is reached.
+pub struct AccountHistoryPages<'a, T> {
+    account: &'a FuturesAccount,
+    symbol: String,
+    start_time: u64,
+    end_time: u64,
+    last_id: Option<u64>,
+    // Id to read the next page from, and the time until which pages are read by id
+    from_id: Option<(u64, u64)>,
+    fetch_page: HistoryPage<T>,
+}
+
+impl<'a, T: HistoryRecord> AccountHistoryPages<'a, T> {
+    fn new(
+        account: &'a FuturesAccount, symbol: String, start_time: u64, end_time: u64,
+        fetch_page: HistoryPage<T>,
+    ) -> Self {
+        Self {
+            account,
+            symbol,
+            start_time,
+            end_time,
+            last_id: None,
+            from_id: None,
+            fetch_page,
+        }
+    }
+
+    fn fetch_by_time(&mut self) -> Result<Vec<T>> {
+        let window_end = self
+            .end_time
+            .min(self.start_time.saturating_add(HISTORY_WINDOW_MS - 1));
+        let records = (self.fetch_page)(
+            self.account,
+            &self.symbol,
+            None,
+            Some(self.start_time),
+            Some(window_end),
+        )?;
+        let full_page = records.len() >= usize::from(HISTORY_PAGE_LIMIT);
+        let last_time = records.iter().rev().find_map(HistoryRecord::time);
+
+        match (records.last(), last_time) {
+            // Records sharing the time of the last one may be on the next page
+            (Some(_), Some(last_time)) if full_page && last_time > self.start_time => {
+                self.start_time = last_time;
+            }
+            (Some(last), last_time) if full_page => {
+                self.from_id = Some((last.id() + 1, last_time.unwrap_or(window_end)));
+            }
+            _ => self.start_time = window_end + 1,
+        }
+
+        Ok(records)
+    }
+
+    fn fetch_by_id(&mut self, from_id: u64, until: u64) -> Result<Vec<T>> {
+        let records = (self.fetch_page)(self.account, &self.symbol, Some(from_id), None, None)?;
+        let mut caught_up = records.len() < usize::from(HISTORY_PAGE_LIMIT);
+
+        let mut page = Vec::with_capacity(records.len());
+        for record in records {
+            if matches!(record.time(), Some(time) if time > until) {
+                caught_up = true;
+                break;
+            }
+            page.push(record);
+        }
+
+        match page.last() {
+            Some(last) if !caught_up => self.from_id = Some((last.id() + 1, until)),
+            _ => {
+                self.from_id = None;
+                self.start_time = until + 1;
+            }
+        }
+
+        Ok(page)
+    }
+}
+
+impl<'a, T: HistoryRecord> PageSource for AccountHistoryPages<'a, T> {
+    type Item = T;
+
+    fn fetch_page(&mut self) -> Result<Vec<T>> {
+        let records = match self.from_id {
+            Some((from_id, until)) => self.fetch_by_id(from_id, until)?,
+            None => self.fetch_by_time()?,
+        };
+
+        let last_id = self.last_id;
+        let records: Vec<T> = records
+            .into_iter()
+            .filter(|record| Some(record.id()) > last_id)
+            .collect();
+        if let Some(last) = records.last() {
+            self.last_id = Some(last.id());
+        }
+
+        Ok(records)
+    }
+
+    fn finished(&self) -> bool {
+        self.from_id.is_none() && self.start_time > self.end_time
+    }
+}
+
+/// Iterator over the income history of the account.
+pub type IncomeHistory<'a> = Pages<IncomePages<'a>>;
+
+/// Pages of an `IncomeHistory`, the time range is read with the `page` parameter until a page
+/// is not full.
+pub struct IncomePages<'a> {
+    account: &'a FuturesAccount,
+    symbol: Option<String>,
+    income_type: Option<IncomeType>,
+    start_time: u64,
+    end_time: u64,
+    page: u32,
+    finished: bool,
+}
+
+impl<'a> PageSource for IncomePages<'a> {
+    type Item = Income;
+
+    fn fetch_page(&mut self) -> Result<Vec<Income>> {
+        let incomes = self.account.get_income(
+            self.symbol.clone(),
+            self.income_type,
+            self.start_time,
+            self.end_time,
+            self.page,
+            HISTORY_PAGE_LIMIT,
+        )?;
+
+        self.finished = incomes.len() < usize::from(HISTORY_PAGE_LIMIT);
+        self.page += 1;
+        Ok(incomes)
+    }
+
+    fn finished(&self) -> bool {
+        self.finished
+    }
 }
@@ -0,0 +1,259 @@
+use std::collections::BTreeMap;
+
+use crate::util::*;
+use crate::errors::*;
+use crate::preflight::{ApiPermission, RequiredPermissions};
+use crate::client::Client;
+use crate::api::{API, Dapi};
+use crate::model::Empty;
+use crate::account::{OrderSide, TimeInForce};
+use crate::futures::account::{OrderType, PositionSide, WorkingType};
+use super::model::{
+    AccountBalance, AccountInformation, ChangeLeverageResponse, CountdownCancelAll, Order,
+    PositionRisk,
+};
+
+#[derive(Clone)]
+pub struct CoinMAccount {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl RequiredPermissions for CoinMAccount {
+    fn required_permissions() -> Vec<ApiPermission> {
+        vec![ApiPermission::Reading, ApiPermission::Futures]
+    }
+}
+
+/// Order on a COIN-M contract, the quantity is a number of contracts.
+pub struct CoinMOrderRequest {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub position_side: Option<PositionSide>,
+    pub order_type: OrderType,
+    pub time_in_force: Option<TimeInForce>,
+    pub quantity: Option<u64>,
+    pub reduce_only: Option<bool>,
+    pub price: Option<f64>,
+    pub new_client_order_id: Option<String>,
+    pub stop_price: Option<f64>,
+    pub close_position: Option<bool>,
+    pub activation_price: Option<f64>,
+    pub callback_rate: Option<f64>,
+    pub working_type: Option<WorkingType>,
+    pub price_protect: Option<bool>,
+}
+
+impl CoinMOrderRequest {
+    pub fn limit<S>(
+        symbol: S, side: OrderSide, quantity: u64, price: f64, time_in_force: TimeInForce,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            time_in_force: Some(time_in_force),
+            quantity: Some(quantity),
+            price: Some(price),
+            ..Self::new(symbol, side, OrderType::Limit)
+        }
+    }
+
+    pub fn market<S>(symbol: S, side: OrderSide, quantity: u64) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            quantity: Some(quantity),
+            ..Self::new(symbol, side, OrderType::Market)
+        }
+    }
+
+    fn new<S>(symbol: S, side: OrderSide, order_type: OrderType) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            symbol: symbol.into(),
+            side,
+            position_side: None,
+            order_type,
+            time_in_force: None,
+            quantity: None,
+            reduce_only: None,
+            price: None,
+            new_client_order_id: None,
+            stop_price: None,
+            close_position: None,
+            activation_price: None,
+            callback_rate: None,
+            working_type: None,
+            price_protect: None,
+        }
+    }
+}
+
+impl CoinMAccount {
+    pub fn new_order(&self, order: CoinMOrderRequest) -> Result<Order> {
+        let order = self.build_order(order);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed(API::CoinFutures(Dapi::Order), request)
+    }
+
+    pub fn cancel_order<S>(&self, symbol: S, order_id: u64) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::CoinFutures(Dapi::Order), Some(request))
+    }
+
+    pub fn cancel_all_open_orders<S>(&self, symbol: S) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed::<Empty>(API::CoinFutures(Dapi::AllOpenOrders), Some(request))
+            .map(|_| ())
+    }
+
+    /// Cancels all open orders of the symbol once countdown_time milliseconds have passed
+    /// without another call, 0 disables the countdown.
+    pub fn countdown_cancel_all<S>(
+        &self, symbol: S, countdown_time: u64,
+    ) -> Result<CountdownCancelAll>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("countdownTime".into(), countdown_time.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::CoinFutures(Dapi::CountdownCancelAll), request)
+    }
+
+    // Open orders of a symbol, of all contracts of a pair, or of all symbols
+    pub fn get_open_orders(
+        &self, symbol: Option<String>, pair: Option<String>,
+    ) -> Result<Vec<Order>> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(symbol) = symbol {
+            parameters.insert("symbol".into(), symbol);
+        }
+        if let Some(pair) = pair {
+            parameters.insert("pair".into(), pair);
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::CoinFutures(Dapi::OpenOrders), Some(request))
+    }
+
+    pub fn account_information(&self) -> Result<AccountInformation> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::CoinFutures(Dapi::Account), Some(request))
+    }
+
+    pub fn account_balance(&self) -> Result<Vec<AccountBalance>> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::CoinFutures(Dapi::Balance), Some(request))
+    }
+
+    // Positions of the contracts margined in margin_asset, or of all contracts of a pair
+    pub fn position_information(
+        &self, margin_asset: Option<String>, pair: Option<String>,
+    ) -> Result<Vec<PositionRisk>> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(margin_asset) = margin_asset {
+            parameters.insert("marginAsset".into(), margin_asset);
+        }
+        if let Some(pair) = pair {
+            parameters.insert("pair".into(), pair);
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::CoinFutures(Dapi::PositionRisk), Some(request))
+    }
+
+    pub fn change_initial_leverage<S>(
+        &self, symbol: S, leverage: u8,
+    ) -> Result<ChangeLeverageResponse>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("leverage".into(), leverage.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::CoinFutures(Dapi::ChangeInitialLeverage), request)
+    }
+
+    fn build_order(&self, order: CoinMOrderRequest) -> BTreeMap<String, String> {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), order.symbol);
+        parameters.insert("side".into(), order.side.into());
+        parameters.insert("type".into(), order.order_type.into());
+
+        if let Some(position_side) = order.position_side {
+            parameters.insert("positionSide".into(), position_side.into());
+        }
+        if let Some(time_in_force) = order.time_in_force {
+            parameters.insert("timeInForce".into(), time_in_force.into());
+        }
+        if let Some(quantity) = order.quantity {
+            parameters.insert("quantity".into(), quantity.to_string());
+        }
+        if let Some(reduce_only) = order.reduce_only {
+            parameters.insert("reduceOnly".into(), reduce_only.to_string().to_uppercase());
+        }
+        if let Some(price) = order.price {
+            parameters.insert("price".into(), price.to_string());
+        }
+        if let Some(client_order_id) = order.new_client_order_id {
+            parameters.insert("newClientOrderId".into(), client_order_id);
+        }
+        if let Some(stop_price) = order.stop_price {
+            parameters.insert("stopPrice".into(), stop_price.to_string());
+        }
+        if let Some(close_position) = order.close_position {
+            parameters.insert(
+                "closePosition".into(),
+                close_position.to_string().to_uppercase(),
+            );
+        }
+        if let Some(activation_price) = order.activation_price {
+            parameters.insert("activationPrice".into(), activation_price.to_string());
+        }
+        if let Some(callback_rate) = order.callback_rate {
+            parameters.insert("callbackRate".into(), callback_rate.to_string());
+        }
+        if let Some(working_type) = order.working_type {
+            parameters.insert("workingType".into(), working_type.into());
+        }
+        if let Some(price_protect) = order.price_protect {
+            parameters.insert(
+                "priceProtect".into(),
+                price_protect.to_string().to_uppercase(),
+            );
+        }
+
+        parameters
+    }
+}
@@ -0,0 +1,136 @@
+use crate::util::*;
+use crate::futures::coin_m::model::*;
+use crate::model::Empty;
+use crate::client::*;
+use crate::errors::*;
+use std::collections::BTreeMap;
+use serde_json::Value;
+use crate::api::API;
+use crate::api::Dapi;
+use std::convert::TryInto;
+
+#[derive(Clone)]
+pub struct CoinMMarket {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl CoinMMarket {
+    // Test connectivity
+    pub fn ping(&self) -> Result<String> {
+        self.client
+            .get::<Empty>(API::CoinFutures(Dapi::Ping), None)?;
+        Ok("pong".into())
+    }
+
+    // Check server time
+    pub fn get_server_time(&self) -> Result<ServerTime> {
+        self.client.get(API::CoinFutures(Dapi::Time), None)
+    }
+
+    // Perpetual and delivery contracts, with their contract size
+    pub fn exchange_info(&self) -> Result<ExchangeInformation> {
+        self.client.get(API::CoinFutures(Dapi::ExchangeInfo), None)
+    }
+
+    // Get Symbol information
+    pub fn get_symbol_info<S>(&self, symbol: S) -> Result<Symbol>
+    where
+        S: Into<String>,
+    {
+        let upper_symbol = symbol.into().to_uppercase();
+        let info = self.exchange_info()?;
+        match info.symbols.into_iter().find(|s| s.symbol == upper_symbol) {
+            Some(symbol) => Ok(symbol),
+            None => bail!("Symbol not found"),
+        }
+    }
+
+    // Order book (Default 500; max 1000)
+    pub fn get_depth<S1, S2>(&self, symbol: S1, limit: S2) -> Result<OrderBook>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), lt.to_string());
+        }
+
+        let request = build_request(parameters);
+        self.client
+            .get(API::CoinFutures(Dapi::Depth), Some(request))
+    }
+
+    // Returns up to 'limit' klines, volumes are in contracts and in base asset
+    pub fn get_klines<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, interval: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("interval".into(), interval.into());
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), lt.to_string());
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), st.to_string());
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), et.to_string());
+        }
+
+        let request = build_request(parameters);
+        let data: Vec<Vec<Value>> = self
+            .client
+            .get(API::CoinFutures(Dapi::Klines), Some(request))?;
+
+        let klines = KlineSummaries::AllKlineSummaries(
+            data.iter()
+                .map(|row| row.try_into())
+                .collect::<Result<Vec<KlineSummary>>>()?,
+        );
+
+        Ok(klines)
+    }
+
+    // Latest prices of a symbol, of all contracts of a pair, or of all symbols
+    pub fn get_prices(
+        &self, symbol: Option<String>, pair: Option<String>,
+    ) -> Result<Vec<SymbolPrice>> {
+        let request = Self::symbol_or_pair(symbol, pair);
+        self.client
+            .get(API::CoinFutures(Dapi::TickerPrice), request)
+    }
+
+    // Mark prices of a symbol, of all contracts of a pair, or of all symbols
+    pub fn get_mark_prices(
+        &self, symbol: Option<String>, pair: Option<String>,
+    ) -> Result<Vec<MarkPrice>> {
+        let request = Self::symbol_or_pair(symbol, pair);
+        self.client
+            .get(API::CoinFutures(Dapi::PremiumIndex), request)
+    }
+
+    fn symbol_or_pair(symbol: Option<String>, pair: Option<String>) -> Option<String> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(symbol) = symbol {
+            parameters.insert("symbol".into(), symbol);
+        }
+        if let Some(pair) = pair {
+            parameters.insert("pair".into(), pair);
+        }
+        if parameters.is_empty() {
+            None
+        } else {
+            Some(build_request(parameters))
+        }
+    }
+}
@@ -0,0 +1,293 @@
+use serde::{Deserialize, Serialize};
+use crate::model::{string_or_float, string_or_bool};
+
+pub use crate::futures::model::{CountdownCancelAll, OrderBook};
+pub use crate::model::{Filters, KlineSummaries, KlineSummary, RateLimit, ServerTime};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeInformation {
+    pub timezone: String,
+    pub server_time: u64,
+    pub rate_limits: Vec<RateLimit>,
+    pub exchange_filters: Vec<String>,
+    pub symbols: Vec<Symbol>,
+}
+
+/// COIN-M contract, either perpetual or delivered at `delivery_date`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Symbol {
+    pub symbol: String,
+    pub pair: String,
+    /// PERPETUAL, CURRENT_QUARTER or NEXT_QUARTER
+    pub contract_type: String,
+    pub delivery_date: u64,
+    pub onboard_date: u64,
+    pub contract_status: String,
+    /// Value of one contract, in the quote asset
+    pub contract_size: u64,
+    pub margin_asset: String,
+    pub base_asset: String,
+    pub quote_asset: String,
+    pub price_precision: u16,
+    pub quantity_precision: u16,
+    pub base_asset_precision: u64,
+    pub quote_precision: u64,
+    pub equal_qty_precision: u16,
+    pub filters: Vec<Filters>,
+    pub order_types: Vec<String>,
+    pub time_in_force: Vec<String>,
+}
+
+impl Symbol {
+    pub fn is_perpetual(&self) -> bool {
+        self.contract_type == "PERPETUAL"
+    }
+
+    /// Amount of base asset worth `contracts` contracts at `price`.
+    pub fn contracts_to_base(&self, contracts: u64, price: f64) -> f64 {
+        contracts as f64 * self.contract_size as f64 / price
+    }
+
+    /// Number of whole contracts worth at most `base_amount` of base asset at `price`.
+    pub fn base_to_contracts(&self, base_amount: f64, price: f64) -> u64 {
+        (base_amount * price / self.contract_size as f64).floor() as u64
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolPrice {
+    pub symbol: String,
+    #[serde(rename = "ps")]
+    pub pair: String,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    pub time: u64,
+}
+
+/// Mark price and funding, the funding fields are empty for delivery contracts.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkPrice {
+    pub symbol: String,
+    pub pair: String,
+    #[serde(with = "string_or_float")]
+    pub mark_price: f64,
+    #[serde(with = "string_or_float")]
+    pub index_price: f64,
+    #[serde(with = "string_or_float")]
+    pub estimated_settle_price: f64,
+    #[serde(with = "empty_or_float")]
+    pub last_funding_rate: Option<f64>,
+    #[serde(with = "empty_or_float")]
+    pub interest_rate: Option<f64>,
+    pub next_funding_time: u64,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Order {
+    pub order_id: u64,
+    pub symbol: String,
+    pub pair: String,
+    pub status: String,
+    pub client_order_id: String,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub avg_price: f64,
+    /// Quantity in contracts
+    #[serde(with = "string_or_float")]
+    pub orig_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: f64,
+    /// Executed quantity in base asset
+    #[serde(with = "string_or_float")]
+    pub cum_base: f64,
+    pub time_in_force: String,
+    #[serde(rename = "type")]
+    pub order_type: String,
+    pub reduce_only: bool,
+    pub close_position: bool,
+    pub side: String,
+    pub position_side: String,
+    #[serde(with = "string_or_float")]
+    pub stop_price: f64,
+    pub working_type: String,
+    pub price_protect: bool,
+    pub orig_type: String,
+    #[serde(default)]
+    pub time: Option<u64>,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInformation {
+    pub assets: Vec<AccountAsset>,
+    pub positions: Vec<AccountPosition>,
+    pub can_deposit: bool,
+    pub can_trade: bool,
+    pub can_withdraw: bool,
+    pub fee_tier: u64,
+    pub update_time: u64,
+}
+
+/// Margin balance, in coin
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountAsset {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub wallet_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub unrealized_profit: f64,
+    #[serde(with = "string_or_float")]
+    pub margin_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub maint_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub position_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub open_order_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub max_withdraw_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub cross_wallet_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub cross_un_pnl: f64,
+    #[serde(with = "string_or_float")]
+    pub available_balance: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountPosition {
+    pub symbol: String,
+    /// Position in contracts
+    #[serde(with = "string_or_float")]
+    pub position_amt: f64,
+    #[serde(with = "string_or_float")]
+    pub initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub maint_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub unrealized_profit: f64,
+    #[serde(with = "string_or_float")]
+    pub position_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub open_order_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub leverage: f64,
+    pub isolated: bool,
+    pub position_side: String,
+    #[serde(with = "string_or_float")]
+    pub entry_price: f64,
+    #[serde(with = "string_or_float")]
+    pub max_qty: f64,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountBalance {
+    pub account_alias: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub balance: f64,
+    #[serde(with = "string_or_float")]
+    pub withdraw_available: f64,
+    #[serde(with = "string_or_float")]
+    pub cross_wallet_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub cross_un_pnl: f64,
+    #[serde(with = "string_or_float")]
+    pub available_balance: f64,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionRisk {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub position_amt: f64,
+    #[serde(with = "string_or_float")]
+    pub entry_price: f64,
+    #[serde(with = "string_or_float")]
+    pub mark_price: f64,
+    #[serde(with = "string_or_float")]
+    pub un_realized_profit: f64,
+    #[serde(with = "string_or_float")]
+    pub liquidation_price: f64,
+    #[serde(with = "string_or_float")]
+    pub leverage: f64,
+    #[serde(with = "string_or_float")]
+    pub max_qty: f64,
+    pub margin_type: String,
+    #[serde(with = "string_or_float")]
+    pub isolated_margin: f64,
+    #[serde(with = "string_or_bool")]
+    pub is_auto_add_margin: bool,
+    pub position_side: String,
+    /// Notional value, in base asset
+    #[serde(with = "string_or_float")]
+    pub notional_value: f64,
+    #[serde(with = "string_or_float")]
+    pub isolated_wallet: f64,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeLeverageResponse {
+    pub leverage: u8,
+    /// Maximum quantity in base asset
+    #[serde(with = "string_or_float")]
+    pub max_qty: f64,
+    pub symbol: String,
+}
+
+// Funding fields are empty strings for delivery contracts
+mod empty_or_float {
+    use std::fmt;
+
+    use serde::{Serializer, Deserializer};
+
+    pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: fmt::Display,
+        S: Serializer,
+    {
+        match value {
+            Some(v) => serializer.collect_str(v),
+            None => serializer.serialize_str(""),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::Deserialize;
+        use serde::de::Error;
+
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum StringOrFloat {
+            String(String),
+            Float(f64),
+        }
+
+        match StringOrFloat::deserialize(deserializer)? {
+            StringOrFloat::String(s) if s.is_empty() => Ok(None),
+            StringOrFloat::String(s) => s.parse().map(Some).map_err(D::Error::custom),
+            StringOrFloat::Float(f) => Ok(Some(f)),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use crate::model::*;
+use crate::client::*;
+use crate::errors::*;
+use crate::preflight::{ApiPermission, RequiredPermissions};
+use crate::api::API;
+use crate::api::Dapi;
+
+#[derive(Clone)]
+pub struct CoinMUserStream {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl RequiredPermissions for CoinMUserStream {
+    fn required_permissions() -> Vec<ApiPermission> {
+        vec![ApiPermission::Reading, ApiPermission::Futures]
+    }
+}
+
+impl CoinMUserStream {
+    // User Stream
+    pub fn start(&self) -> Result<UserDataStream> {
+        self.client.post(API::CoinFutures(Dapi::UserDataStream))
+    }
+
+    pub fn keep_alive(&self, listen_key: &str) -> Result<Success> {
+        self.client
+            .put(API::CoinFutures(Dapi::UserDataStream), listen_key)
+    }
+
+    pub fn close(&self, listen_key: &str) -> Result<Success> {
+        self.client
+            .delete(API::CoinFutures(Dapi::UserDataStream), listen_key)
+    }
+}
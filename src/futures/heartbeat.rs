@@ -0,0 +1,164 @@
+/*!
+Dead man's switch on top of `countdownCancelAll`.
+
+A `CountdownHeartbeat` refreshes the countdown of its symbols from a background thread, but
+only after `alive` has been called since the previous refresh. When the owner stops calling
+`alive`, because it hangs or lost its connection, the countdown runs out and the exchange
+cancels all open orders of the symbols.
+
+USD-M and COIN-M futures, and European options by underlying, have a countdown. The spot
+API has none, so spot orders are not covered.
+*/
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use crate::errors::*;
+use crate::futures::account::FuturesAccount;
+use crate::futures::coin_m::account::CoinMAccount;
+use crate::options::account::OptionsAccount;
+
+/// Account whose open orders can be canceled by a countdown.
+pub trait CountdownCancel {
+    fn set_countdown(&self, symbol: &str, countdown_time: u64) -> Result<()>;
+
+    /// Restarts the countdown, by setting it again unless the account has a heartbeat.
+    fn refresh_countdown(&self, symbol: &str, countdown_time: u64) -> Result<()> {
+        self.set_countdown(symbol, countdown_time)
+    }
+}
+
+impl CountdownCancel for FuturesAccount {
+    fn set_countdown(&self, symbol: &str, countdown_time: u64) -> Result<()> {
+        self.countdown_cancel_all(symbol, countdown_time)
+            .map(|_| ())
+    }
+}
+
+impl CountdownCancel for CoinMAccount {
+    fn set_countdown(&self, symbol: &str, countdown_time: u64) -> Result<()> {
+        self.countdown_cancel_all(symbol, countdown_time)
+            .map(|_| ())
+    }
+}
+
+// Symbols are underlyings, e.g. BTCUSDT
+impl CountdownCancel for OptionsAccount {
+    fn set_countdown(&self, symbol: &str, countdown_time: u64) -> Result<()> {
+        self.countdown_cancel_all(symbol, countdown_time)
+            .map(|_| ())
+    }
+
+    fn refresh_countdown(&self, symbol: &str, _countdown_time: u64) -> Result<()> {
+        self.countdown_cancel_all_heartbeat(&[symbol]).map(|_| ())
+    }
+}
+
+pub struct CountdownHeartbeat<A: CountdownCancel> {
+    account: A,
+    symbols: Vec<String>,
+    alive: Arc<AtomicBool>,
+    last_error: Arc<Mutex<Option<String>>>,
+    stop: Option<Sender<()>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl<A> CountdownHeartbeat<A>
+where
+    A: CountdownCancel + Clone + Send + 'static,
+{
+    /// Sets the countdown of the symbols, then refreshes it every `interval` while `alive`
+    /// keeps being called. The interval must be shorter than the countdown.
+    pub fn start(
+        account: A, symbols: Vec<String>, countdown_time: Duration, interval: Duration,
+    ) -> Result<Self> {
+        if symbols.is_empty() {
+            bail!("At least one symbol must be given");
+        }
+        if interval >= countdown_time {
+            bail!(format!(
+                "The interval ({:?}) must be shorter than the countdown ({:?})",
+                interval, countdown_time
+            ));
+        }
+        let countdown_ms = countdown_time.as_millis() as u64;
+        for symbol in &symbols {
+            account.set_countdown(symbol, countdown_ms)?;
+        }
+
+        let alive = Arc::new(AtomicBool::new(false));
+        let last_error = Arc::new(Mutex::new(None));
+        let (stop, stopped) = mpsc::channel();
+
+        let handle = {
+            let account = account.clone();
+            let symbols = symbols.clone();
+            let alive = alive.clone();
+            let last_error = last_error.clone();
+            thread::spawn(move || {
+                while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(interval) {
+                    if !alive.swap(false, Ordering::SeqCst) {
+                        continue;
+                    }
+                    let mut error = None;
+                    for symbol in &symbols {
+                        if let Err(e) = account.refresh_countdown(symbol, countdown_ms) {
+                            error.get_or_insert(format!("{}: {}", symbol, e));
+                        }
+                    }
+                    *last_error.lock().unwrap() = error;
+                }
+            })
+        };
+
+        Ok(Self {
+            account,
+            symbols,
+            alive,
+            last_error,
+            stop: Some(stop),
+            handle: Some(handle),
+        })
+    }
+
+    /// Reports the owner as healthy, the countdown is refreshed at the next interval.
+    pub fn alive(&self) {
+        self.alive.store(true, Ordering::SeqCst);
+    }
+
+    /// Error of the last refresh, `None` once a refresh succeeds again. A failing refresh
+    /// lets the countdown run out.
+    pub fn last_error(&self) -> Option<String> {
+        self.last_error.lock().unwrap().clone()
+    }
+
+    /// Stops refreshing and disables the countdown, the open orders are kept.
+    pub fn stop(mut self) -> Result<()> {
+        self.stop_thread();
+        for symbol in &self.symbols {
+            self.account.set_countdown(symbol, 0)?;
+        }
+        Ok(())
+    }
+}
+
+impl<A: CountdownCancel> CountdownHeartbeat<A> {
+    fn stop_thread(&mut self) {
+        if let Some(stop) = self.stop.take() {
+            let _ = stop.send(());
+        }
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl<A: CountdownCancel> Drop for CountdownHeartbeat<A> {
+    // Stops refreshing without disabling the countdown, so the orders get canceled
+    fn drop(&mut self) {
+        self.stop_thread();
+    }
+}
@@ -5,19 +5,23 @@
 - [ ] `Old Trades Lookup (MARKET_DATA)`
 - [x] `Compressed/Aggregate Trades List`
 - [x] `Kline/Candlestick Data`
+- [x] `Continuous Contract Kline/Candlestick Data`
+- [x] `Index Price Kline/Candlestick Data`
+- [x] `Mark Price Kline/Candlestick Data`
+- [x] `Premium Index Kline Data`
 - [x] `Mark Price`
-- [ ] `Get Funding Rate History (MARKET_DATA)`
+- [x] `Get Funding Rate History (MARKET_DATA)`
 - [x] `24hr Ticker Price Change Statistics`
 - [x] `Symbol Price Ticker`
 - [x] `Symbol Order Book Ticker`
 - [x] `Get all Liquidation Orders`
 - [x] `Open Interest`
 - [ ] `Notional and Leverage Brackets (MARKET_DATA)`
-- [ ] `Open Interest Statistics (MARKET_DATA)`
-- [ ] `Top Trader Long/Short Ratio (Accounts) (MARKET_DATA)`
-- [ ] `Top Trader Long/Short Ratio (Positions) (MARKET_DATA)`
-- [ ] `Long/Short Ratio (MARKET_DATA)`
-- [ ] `Taker Buy/Sell Volume (MARKET_DATA)`
+- [x] `Open Interest Statistics (MARKET_DATA)`
+- [x] `Top Trader Long/Short Ratio (Accounts) (MARKET_DATA)`
+- [x] `Top Trader Long/Short Ratio (Positions) (MARKET_DATA)`
+- [x] `Long/Short Ratio (MARKET_DATA)`
+- [x] `Taker Buy/Sell Volume (MARKET_DATA)`
 */
 
 use crate::util::*;
@@ -28,8 +32,12 @@ use std::collections::BTreeMap;
 use serde_json::Value;
 use crate::api::API;
 use crate::api::Futures;
+use crate::futures::account::ContractType;
 use std::convert::TryInto;
 
+/// Largest page returned by the funding rate history request.
+pub const FUNDING_RATE_PAGE_LIMIT: u16 = 1000;
+
 // TODO
 // Make enums for Strings
 // Add limit parameters to functions
@@ -183,6 +191,108 @@ impl FuturesMarket {
         Ok(klines)
     }
 
+    // Klines of a contract type of a pair, e.g. the current quarter of BTCUSDT
+    pub fn get_continuous_klines<S1, S2, S3, S4, S5>(
+        &self, pair: S1, contract_type: ContractType, interval: S2, limit: S3, start_time: S4,
+        end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters = Self::kline_parameters(
+            "pair",
+            pair.into(),
+            interval.into(),
+            limit.into(),
+            start_time.into(),
+            end_time.into(),
+        );
+        parameters.insert("contractType".into(), contract_type.into());
+
+        let request = build_request(parameters);
+        let data: Vec<Vec<Value>> = self
+            .client
+            .get(API::Futures(Futures::ContinuousKlines), Some(request))?;
+
+        let klines = KlineSummaries::AllKlineSummaries(
+            data.iter()
+                .map(|row| row.try_into())
+                .collect::<Result<Vec<KlineSummary>>>()?,
+        );
+
+        Ok(klines)
+    }
+
+    // Klines of the index price of a pair
+    pub fn get_index_price_klines<S1, S2, S3, S4, S5>(
+        &self, pair: S1, interval: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<Vec<PriceKline>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let parameters = Self::kline_parameters(
+            "pair",
+            pair.into(),
+            interval.into(),
+            limit.into(),
+            start_time.into(),
+            end_time.into(),
+        );
+        self.get_price_klines(Futures::IndexPriceKlines, parameters)
+    }
+
+    // Klines of the mark price of a symbol
+    pub fn get_mark_price_klines<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, interval: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<Vec<PriceKline>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let parameters = Self::kline_parameters(
+            "symbol",
+            symbol.into(),
+            interval.into(),
+            limit.into(),
+            start_time.into(),
+            end_time.into(),
+        );
+        self.get_price_klines(Futures::MarkPriceKlines, parameters)
+    }
+
+    // Klines of the premium index of a symbol
+    pub fn get_premium_index_klines<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, interval: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<Vec<PriceKline>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let parameters = Self::kline_parameters(
+            "symbol",
+            symbol.into(),
+            interval.into(),
+            limit.into(),
+            start_time.into(),
+            end_time.into(),
+        );
+        self.get_price_klines(Futures::PremiumIndexKlines, parameters)
+    }
+
     // 24hr ticker price change statistics
     pub fn get_24h_price_stats<S>(&self, symbol: S) -> Result<PriceStats>
     where
@@ -286,4 +396,277 @@ impl FuturesMarket {
         self.client
             .get(API::Futures(Futures::OpenInterestHist), Some(request))
     }
+
+    // Funding rate history, the most recent first 100 if no time range is given (max 1000)
+    pub fn get_funding_rate_history<S1, S2, S3>(
+        &self, symbol: Option<String>, start_time: S1, end_time: S2, limit: S3,
+    ) -> Result<Vec<FundingRate>>
+    where
+        S1: Into<Option<u64>>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(symbol) = symbol {
+            parameters.insert("symbol".into(), symbol);
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), st.to_string());
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), et.to_string());
+        }
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), lt.to_string());
+        }
+
+        let request = build_request(parameters);
+        self.client
+            .get(API::Futures(Futures::FundingRate), Some(request))
+    }
+
+    /// Funding rates of a symbol between start_time and end_time, read page by page.
+    pub fn funding_rate_history_range<S>(
+        &self, symbol: S, start_time: u64, end_time: u64,
+    ) -> FundingRateHistory<'_>
+    where
+        S: Into<String>,
+    {
+        Pages::new(FundingRatePages {
+            market: self,
+            symbol: symbol.into(),
+            start_time,
+            end_time,
+            finished: start_time > end_time,
+        })
+    }
+
+    // Funding rate cap, floor and interval of the symbols with adjusted funding
+    pub fn get_funding_info(&self) -> Result<Vec<FundingInfo>> {
+        self.client.get(API::Futures(Futures::FundingInfo), None)
+    }
+
+    // Mark price, index price and funding rate of ONE symbol
+    pub fn get_premium_index<S>(&self, symbol: S) -> Result<MarkPrice>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        let request = build_request(parameters);
+        self.client
+            .get(API::Futures(Futures::PremiumIndex), Some(request))
+    }
+
+    pub fn top_long_short_account_ratio<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, period: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<Vec<LongShortRatio>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let request = Self::ratio_request(symbol, period, limit, start_time, end_time);
+        self.client.get(
+            API::Futures(Futures::TopLongShortAccountRatio),
+            Some(request),
+        )
+    }
+
+    pub fn top_long_short_position_ratio<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, period: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<Vec<LongShortRatio>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let request = Self::ratio_request(symbol, period, limit, start_time, end_time);
+        self.client.get(
+            API::Futures(Futures::TopLongShortPositionRatio),
+            Some(request),
+        )
+    }
+
+    pub fn global_long_short_account_ratio<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, period: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<Vec<LongShortRatio>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let request = Self::ratio_request(symbol, period, limit, start_time, end_time);
+        self.client.get(
+            API::Futures(Futures::GlobalLongShortAccountRatio),
+            Some(request),
+        )
+    }
+
+    pub fn taker_long_short_ratio<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, period: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<Vec<TakerLongShortRatio>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let request = Self::ratio_request(symbol, period, limit, start_time, end_time);
+        self.client
+            .get(API::Futures(Futures::TakerlongshortRatio), Some(request))
+    }
+
+    // Klines of the net asset value of a leveraged token, e.g. BTCDOWN
+    pub fn get_lvt_klines<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, interval: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<Vec<LvtKline>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("interval".into(), interval.into());
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), format!("{}", lt));
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), format!("{}", st));
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), format!("{}", et));
+        }
+
+        let request = build_request(parameters);
+        let data: Vec<Vec<Value>> = self
+            .client
+            .get(API::Futures(Futures::LvtKlines), Some(request))?;
+
+        data.iter().map(|row| row.try_into()).collect()
+    }
+
+    // Composition of ONE composite index
+    pub fn get_index_info<S>(&self, symbol: S) -> Result<IndexInfo>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        let request = build_request(parameters);
+        self.client
+            .get(API::Futures(Futures::IndexInfo), Some(request))
+    }
+
+    // Composition of all composite indexes
+    pub fn get_all_index_info(&self) -> Result<Vec<IndexInfo>> {
+        self.client.get(API::Futures(Futures::IndexInfo), None)
+    }
+
+    fn kline_parameters(
+        key: &str, value: String, interval: String, limit: Option<u16>, start_time: Option<u64>,
+        end_time: Option<u64>,
+    ) -> BTreeMap<String, String> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert(key.into(), value);
+        parameters.insert("interval".into(), interval);
+
+        if let Some(lt) = limit {
+            parameters.insert("limit".into(), format!("{}", lt));
+        }
+        if let Some(st) = start_time {
+            parameters.insert("startTime".into(), format!("{}", st));
+        }
+        if let Some(et) = end_time {
+            parameters.insert("endTime".into(), format!("{}", et));
+        }
+
+        parameters
+    }
+
+    fn get_price_klines(
+        &self, route: Futures, parameters: BTreeMap<String, String>,
+    ) -> Result<Vec<PriceKline>> {
+        let request = build_request(parameters);
+        let data: Vec<Vec<Value>> = self.client.get(API::Futures(route), Some(request))?;
+
+        data.iter().map(|row| row.try_into()).collect()
+    }
+
+    fn ratio_request<S1, S2, S3, S4, S5>(
+        symbol: S1, period: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> String
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("period".into(), period.into());
+
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), format!("{}", lt));
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), format!("{}", st));
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), format!("{}", et));
+        }
+
+        build_request(parameters)
+    }
+}
+
+/// Iterator over the funding rate history of a symbol.
+pub type FundingRateHistory<'a> = Pages<FundingRatePages<'a>>;
+
+/// Pages of a `FundingRateHistory`, each one starts right after the funding time of the last
+/// rate of the previous page.
+pub struct FundingRatePages<'a> {
+    market: &'a FuturesMarket,
+    symbol: String,
+    start_time: u64,
+    end_time: u64,
+    finished: bool,
+}
+
+impl<'a> PageSource for FundingRatePages<'a> {
+    type Item = FundingRate;
+
+    fn fetch_page(&mut self) -> Result<Vec<FundingRate>> {
+        let rates = self.market.get_funding_rate_history(
+            Some(self.symbol.clone()),
+            self.start_time,
+            self.end_time,
+            FUNDING_RATE_PAGE_LIMIT,
+        )?;
+
+        match rates.last() {
+            Some(last) if rates.len() >= usize::from(FUNDING_RATE_PAGE_LIMIT) => {
+                self.start_time = last.funding_time + 1;
+                self.finished = self.start_time > self.end_time;
+            }
+            _ => self.finished = true,
+        }
+
+        Ok(rates)
+    }
+
+    fn finished(&self) -> bool {
+        self.finished
+    }
 }
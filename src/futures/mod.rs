@@ -1,5 +1,7 @@
 pub mod account;
+pub mod coin_m;
 pub mod general;
+pub mod heartbeat;
 pub mod market;
 pub mod model;
 pub mod userstream;
@@ -1,5 +1,8 @@
 use serde::{Deserialize, Serialize};
-use crate::model::{string_or_float, string_or_float_opt, string_or_bool};
+use crate::model::{get_value, string_or_float, string_or_float_opt, string_or_bool, string_or_u64};
+use crate::errors::*;
+use serde_json::{from_value, Value};
+use std::convert::TryFrom;
 
 pub use crate::model::{
     Asks, Bids, BookTickers, Filters, KlineSummaries, KlineSummary, RateLimit, ServerTime,
@@ -134,7 +137,13 @@ pub struct MarkPrice {
     #[serde(with = "string_or_float")]
     pub mark_price: f64,
     #[serde(with = "string_or_float")]
+    pub index_price: f64,
+    #[serde(with = "string_or_float")]
+    pub estimated_settle_price: f64,
+    #[serde(with = "string_or_float")]
     pub last_funding_rate: f64,
+    #[serde(with = "string_or_float")]
+    pub interest_rate: f64,
     pub next_funding_time: u64,
     pub time: u64,
 }
@@ -214,9 +223,79 @@ pub struct Order {
     pub activation_price: f64,
     #[serde(with = "string_or_float", default = "default_price_rate")]
     pub price_rate: f64,
+    /// Creation time, missing from some responses
+    #[serde(default)]
+    pub time: Option<u64>,
     pub update_time: u64,
     pub working_type: String,
+    #[serde(default)]
     pub price_protect: bool,
+    #[serde(default)]
+    pub price_match: Option<String>,
+}
+
+/// Countdown after which all open orders of a symbol are canceled, 0 when disabled
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CountdownCancelAll {
+    pub symbol: String,
+    #[serde(with = "string_or_u64")]
+    pub countdown_time: u64,
+}
+
+/// Modification of an order, with the values before and after it
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderAmendment {
+    pub amendment_id: u64,
+    pub symbol: String,
+    pub pair: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+    pub time: u64,
+    pub amendment: Amendment,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Amendment {
+    pub price: AmendedValue,
+    pub orig_qty: AmendedValue,
+    /// Number of modifications of the order so far
+    pub count: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AmendedValue {
+    #[serde(with = "string_or_float")]
+    pub before: f64,
+    #[serde(with = "string_or_float")]
+    pub after: f64,
+}
+
+/// Fill of an order of the account
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserTrade {
+    pub id: u64,
+    pub order_id: u64,
+    pub symbol: String,
+    pub side: String,
+    pub position_side: String,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub qty: f64,
+    #[serde(with = "string_or_float")]
+    pub quote_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub realized_pnl: f64,
+    #[serde(with = "string_or_float")]
+    pub commission: f64,
+    pub commission_asset: String,
+    pub buyer: bool,
+    pub maker: bool,
+    pub time: u64,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -255,6 +334,9 @@ pub struct Transaction {
     pub update_time: u64,
     pub working_type: String,
     price_protect: bool,
+    /// NONE unless the price follows the order book
+    #[serde(default)]
+    pub price_match: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -295,6 +377,204 @@ pub struct CanceledOrder {
     price_protect: bool,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionMode {
+    /// true in Hedge Mode, false in One-way Mode
+    pub dual_side_position: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MultiAssetsMargin {
+    pub multi_assets_margin: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionMarginResponse {
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub code: i64,
+    pub msg: String,
+    /// 1 for an addition, 2 for a reduction
+    #[serde(rename = "type")]
+    pub adjustment_type: u8,
+}
+
+/// Change of the margin of an isolated position
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionMarginChange {
+    pub symbol: String,
+    /// 1 for an addition, 2 for a reduction
+    #[serde(rename = "type")]
+    pub adjustment_type: u8,
+    #[serde(default)]
+    pub delta_type: Option<String>,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub asset: String,
+    pub time: u64,
+    pub position_side: String,
+}
+
+/// Kind of a change of the balance of the futures account
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum IncomeType {
+    Transfer,
+    WelcomeBonus,
+    RealizedPnl,
+    FundingFee,
+    Commission,
+    InsuranceClear,
+    ReferralKickback,
+    CommissionRebate,
+    ApiRebate,
+    ContestReward,
+    CrossCollateralTransfer,
+    OptionsPremiumFee,
+    OptionsSettleProfit,
+    InternalTransfer,
+    AutoExchange,
+    // Spelled this way by the API
+    #[serde(rename = "DELIVERED_SETTELMENT")]
+    DeliveredSettlement,
+    CoinSwapDeposit,
+    CoinSwapWithdraw,
+    PositionLimitIncreaseFee,
+    #[serde(other)]
+    Unknown,
+}
+
+impl From<IncomeType> for String {
+    fn from(item: IncomeType) -> Self {
+        match item {
+            IncomeType::Transfer => String::from("TRANSFER"),
+            IncomeType::WelcomeBonus => String::from("WELCOME_BONUS"),
+            IncomeType::RealizedPnl => String::from("REALIZED_PNL"),
+            IncomeType::FundingFee => String::from("FUNDING_FEE"),
+            IncomeType::Commission => String::from("COMMISSION"),
+            IncomeType::InsuranceClear => String::from("INSURANCE_CLEAR"),
+            IncomeType::ReferralKickback => String::from("REFERRAL_KICKBACK"),
+            IncomeType::CommissionRebate => String::from("COMMISSION_REBATE"),
+            IncomeType::ApiRebate => String::from("API_REBATE"),
+            IncomeType::ContestReward => String::from("CONTEST_REWARD"),
+            IncomeType::CrossCollateralTransfer => String::from("CROSS_COLLATERAL_TRANSFER"),
+            IncomeType::OptionsPremiumFee => String::from("OPTIONS_PREMIUM_FEE"),
+            IncomeType::OptionsSettleProfit => String::from("OPTIONS_SETTLE_PROFIT"),
+            IncomeType::InternalTransfer => String::from("INTERNAL_TRANSFER"),
+            IncomeType::AutoExchange => String::from("AUTO_EXCHANGE"),
+            IncomeType::DeliveredSettlement => String::from("DELIVERED_SETTELMENT"),
+            IncomeType::CoinSwapDeposit => String::from("COIN_SWAP_DEPOSIT"),
+            IncomeType::CoinSwapWithdraw => String::from("COIN_SWAP_WITHDRAW"),
+            IncomeType::PositionLimitIncreaseFee => String::from("POSITION_LIMIT_INCREASE_FEE"),
+            IncomeType::Unknown => String::from("UNKNOWN"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Income {
+    /// Empty for the changes not tied to a symbol, e.g. transfers
+    pub symbol: String,
+    pub income_type: IncomeType,
+    #[serde(with = "string_or_float")]
+    pub income: f64,
+    pub asset: String,
+    pub info: String,
+    pub time: u64,
+    pub tran_id: u64,
+    pub trade_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadId {
+    pub avg_cost_timestamp_of_last30d: u64,
+    pub download_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadLink {
+    pub download_id: String,
+    /// processing or completed
+    pub status: String,
+    /// Empty until the file is ready
+    pub url: String,
+    pub notified: bool,
+    pub expiration_timestamp: i64,
+    #[serde(default)]
+    pub is_expired: Option<bool>,
+}
+
+/// Notional tiers of a symbol, the leverage allowed gets lower as the notional grows
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LeverageBracket {
+    pub symbol: String,
+    #[serde(default)]
+    pub notional_coef: Option<f64>,
+    pub brackets: Vec<NotionalBracket>,
+}
+
+impl LeverageBracket {
+    /// Tier of a position of the given notional value
+    pub fn bracket(&self, notional: f64) -> Option<&NotionalBracket> {
+        self.brackets
+            .iter()
+            .find(|bracket| notional >= bracket.notional_floor && notional < bracket.notional_cap)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct NotionalBracket {
+    pub bracket: u16,
+    pub initial_leverage: u16,
+    pub notional_cap: f64,
+    pub notional_floor: f64,
+    pub maint_margin_ratio: f64,
+    /// Maintenance amount, subtracted from notional * maint_margin_ratio
+    pub cum: f64,
+}
+
+/// ADL queue position from 0 to 4, the higher the sooner the position is reduced
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AdlQuantile {
+    pub symbol: String,
+    pub adl_quantile: AdlQuantileValues,
+}
+
+/// Quantiles by position side, BOTH in One-way Mode and LONG/SHORT in Hedge Mode
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "UPPERCASE")]
+pub struct AdlQuantileValues {
+    #[serde(default)]
+    pub long: Option<u8>,
+    #[serde(default)]
+    pub short: Option<u8>,
+    #[serde(default)]
+    pub both: Option<u8>,
+    /// Highest of LONG and SHORT, set in Hedge Mode only
+    #[serde(default)]
+    pub hedge: Option<u8>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CommissionRate {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub maker_commission_rate: f64,
+    #[serde(with = "string_or_float")]
+    pub taker_commission_rate: f64,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct PositionRisk {
@@ -586,3 +866,446 @@ pub struct OrderTradeEvent {
     #[serde(rename = "o")]
     pub order: OrderUpdate,
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AccountUpdateEvent {
+    #[serde(rename = "e")]
+    pub event_type: String,
+
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+
+    #[serde(rename = "a")]
+    pub data: AccountUpdateData,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AccountUpdateData {
+    /// Reason of the update, e.g. ORDER, FUNDING_FEE, DEPOSIT
+    #[serde(rename = "m")]
+    pub reason: String,
+
+    #[serde(rename = "B")]
+    pub balances: Vec<BalanceUpdate>,
+
+    /// Positions changed by the update only
+    #[serde(rename = "P")]
+    pub positions: Vec<PositionUpdate>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BalanceUpdate {
+    #[serde(rename = "a")]
+    pub asset: String,
+
+    #[serde(rename = "wb")]
+    pub wallet_balance: String,
+
+    #[serde(rename = "cw")]
+    pub cross_wallet_balance: String,
+
+    /// Change of the balance, excluding PnL and commission
+    #[serde(rename = "bc")]
+    pub balance_change: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PositionUpdate {
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "pa")]
+    pub position_amount: String,
+
+    #[serde(rename = "ep")]
+    pub entry_price: String,
+
+    #[serde(rename = "bep", default)]
+    pub breakeven_price: Option<String>,
+
+    #[serde(rename = "cr")]
+    pub accumulated_realized: String,
+
+    #[serde(rename = "up")]
+    pub unrealized_pnl: String,
+
+    #[serde(rename = "mt")]
+    pub margin_type: String,
+
+    #[serde(rename = "iw")]
+    pub isolated_wallet: String,
+
+    #[serde(rename = "ps")]
+    pub position_side: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MarginCallEvent {
+    #[serde(rename = "e")]
+    pub event_type: String,
+
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    /// Only pushed for crossed positions
+    #[serde(rename = "cw", default)]
+    pub cross_wallet_balance: Option<String>,
+
+    #[serde(rename = "p")]
+    pub positions: Vec<MarginCallPosition>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MarginCallPosition {
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "ps")]
+    pub position_side: String,
+
+    #[serde(rename = "pa")]
+    pub position_amount: String,
+
+    #[serde(rename = "mt")]
+    pub margin_type: String,
+
+    #[serde(rename = "iw")]
+    pub isolated_wallet: String,
+
+    #[serde(rename = "mp")]
+    pub mark_price: String,
+
+    #[serde(rename = "up")]
+    pub unrealized_pnl: String,
+
+    #[serde(rename = "mm")]
+    pub maintenance_margin_required: String,
+}
+
+/// Change of the leverage of a symbol, or of the multi-assets mode
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AccountConfigUpdateEvent {
+    #[serde(rename = "e")]
+    pub event_type: String,
+
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+
+    #[serde(rename = "ac", default)]
+    pub leverage: Option<LeverageUpdate>,
+
+    #[serde(rename = "ai", default)]
+    pub multi_assets: Option<MultiAssetsUpdate>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LeverageUpdate {
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "l")]
+    pub leverage: u16,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MultiAssetsUpdate {
+    #[serde(rename = "j")]
+    pub multi_assets_margin: bool,
+}
+
+/// Fill of an order, with fewer fields and less latency than ORDER_TRADE_UPDATE
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TradeLiteEvent {
+    #[serde(rename = "e")]
+    pub event_type: String,
+
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "q")]
+    pub qty: String,
+
+    #[serde(rename = "p")]
+    pub price: String,
+
+    #[serde(rename = "m")]
+    pub is_maker: bool,
+
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+
+    #[serde(rename = "S")]
+    pub side: String,
+
+    #[serde(rename = "L")]
+    pub price_last_filled_trade: String,
+
+    #[serde(rename = "l")]
+    pub qty_last_filled_trade: String,
+
+    #[serde(rename = "t")]
+    pub trade_id: i64,
+
+    #[serde(rename = "i")]
+    pub order_id: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StrategyUpdateEvent {
+    #[serde(rename = "e")]
+    pub event_type: String,
+
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+
+    #[serde(rename = "su")]
+    pub strategy: StrategyUpdate,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StrategyUpdate {
+    #[serde(rename = "si")]
+    pub strategy_id: u64,
+
+    #[serde(rename = "st")]
+    pub strategy_type: String,
+
+    #[serde(rename = "ss")]
+    pub strategy_status: String,
+
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "ut")]
+    pub update_time: u64,
+
+    /// Operation code, e.g. 8 when the strategy is canceled by the user
+    #[serde(rename = "c")]
+    pub op_code: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GridUpdateEvent {
+    #[serde(rename = "e")]
+    pub event_type: String,
+
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+
+    #[serde(rename = "gu")]
+    pub grid: GridUpdate,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GridUpdate {
+    #[serde(rename = "si")]
+    pub strategy_id: u64,
+
+    #[serde(rename = "st")]
+    pub strategy_type: String,
+
+    #[serde(rename = "ss")]
+    pub strategy_status: String,
+
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "r")]
+    pub realized_pnl: String,
+
+    #[serde(rename = "up")]
+    pub unmatched_average_price: String,
+
+    #[serde(rename = "uq")]
+    pub unmatched_qty: String,
+
+    #[serde(rename = "uf")]
+    pub unmatched_fee: String,
+
+    #[serde(rename = "mp")]
+    pub matched_pnl: String,
+
+    #[serde(rename = "ut")]
+    pub update_time: u64,
+}
+
+/// The listen key is no longer valid, a new user data stream must be started
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ListenKeyExpiredEvent {
+    #[serde(rename = "e")]
+    pub event_type: String,
+
+    // Sent as a string by some versions of the stream
+    #[serde(rename = "E", with = "string_or_u64")]
+    pub event_time: u64,
+
+    pub listen_key: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRate {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub funding_rate: f64,
+    pub funding_time: u64,
+    #[serde(default, with = "string_or_float_opt")]
+    pub mark_price: Option<f64>,
+}
+
+/// Funding rate cap, floor and interval of the symbols with adjusted funding
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingInfo {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub adjusted_funding_rate_cap: f64,
+    #[serde(with = "string_or_float")]
+    pub adjusted_funding_rate_floor: f64,
+    pub funding_interval_hours: u16,
+    #[serde(default)]
+    pub disclaimer: bool,
+}
+
+/// Long/short ratio of accounts or positions, long_account and short_account are
+/// the shares of longs and shorts
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LongShortRatio {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub long_short_ratio: f64,
+    #[serde(with = "string_or_float")]
+    pub long_account: f64,
+    #[serde(with = "string_or_float")]
+    pub short_account: f64,
+    #[serde(with = "string_or_u64")]
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TakerLongShortRatio {
+    #[serde(with = "string_or_float")]
+    pub buy_sell_ratio: f64,
+    #[serde(with = "string_or_float")]
+    pub buy_vol: f64,
+    #[serde(with = "string_or_float")]
+    pub sell_vol: f64,
+    #[serde(with = "string_or_u64")]
+    pub timestamp: u64,
+}
+
+/// Kline of a price without trades: index price, mark price or premium index.
+/// The volume columns of these klines are always zero and are left out.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PriceKline {
+    pub open_time: i64,
+
+    pub open: String,
+
+    pub high: String,
+
+    pub low: String,
+
+    pub close: String,
+
+    pub close_time: i64,
+
+    /// Number of price updates in the kline
+    pub basic_count: i64,
+}
+
+impl TryFrom<&Vec<Value>> for PriceKline {
+    type Error = Error;
+
+    fn try_from(row: &Vec<Value>) -> Result<Self> {
+        Ok(Self {
+            open_time: from_value(get_value(row, 0, "open_time")?)?,
+            open: from_value(get_value(row, 1, "open")?)?,
+            high: from_value(get_value(row, 2, "high")?)?,
+            low: from_value(get_value(row, 3, "low")?)?,
+            close: from_value(get_value(row, 4, "close")?)?,
+            close_time: from_value(get_value(row, 6, "close_time")?)?,
+            basic_count: from_value(get_value(row, 8, "basic_count")?)?,
+        })
+    }
+}
+
+/// Kline of the net asset value of a leveraged token
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LvtKline {
+    pub open_time: i64,
+
+    pub open: String,
+
+    pub high: String,
+
+    pub low: String,
+
+    pub close: String,
+
+    /// Real-time leverage at close
+    pub leverage: String,
+
+    pub close_time: i64,
+
+    pub nav_updates: i64,
+}
+
+impl TryFrom<&Vec<Value>> for LvtKline {
+    type Error = Error;
+
+    fn try_from(row: &Vec<Value>) -> Result<Self> {
+        Ok(Self {
+            open_time: from_value(get_value(row, 0, "open_time")?)?,
+            open: from_value(get_value(row, 1, "open")?)?,
+            high: from_value(get_value(row, 2, "high")?)?,
+            low: from_value(get_value(row, 3, "low")?)?,
+            close: from_value(get_value(row, 4, "close")?)?,
+            leverage: from_value(get_value(row, 5, "leverage")?)?,
+            close_time: from_value(get_value(row, 6, "close_time")?)?,
+            nav_updates: from_value(get_value(row, 8, "nav_updates")?)?,
+        })
+    }
+}
+
+/// Composition of a composite index, e.g. DEFIUSDT
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexInfo {
+    pub symbol: String,
+    pub time: u64,
+    pub component: String,
+    pub base_asset_list: Vec<IndexComponent>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexComponent {
+    pub base_asset: String,
+    pub quote_asset: String,
+    #[serde(with = "string_or_float")]
+    pub weight_in_quantity: f64,
+    #[serde(with = "string_or_float")]
+    pub weight_in_percentage: f64,
+}
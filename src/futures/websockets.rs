@@ -17,6 +17,7 @@ enum FuturesWebsocketAPI {
     Default,
     MultiStream,
     Custom(String),
+    CustomMultiStream(String),
 }
 
 pub enum FuturesMarket {
@@ -40,7 +41,26 @@ impl FuturesWebsocketAPI {
             FuturesWebsocketAPI::MultiStream => {
                 format!("{}/stream?streams={}", baseurl, subscription)
             }
-            FuturesWebsocketAPI::Custom(url) => url,
+            FuturesWebsocketAPI::Custom(url) => {
+                format!("{}/{}", url.trim_end_matches('/'), subscription)
+            }
+            // Endpoints are configured with their raw stream path (`/ws`), combined streams
+            // are served next to it
+            FuturesWebsocketAPI::CustomMultiStream(url) => format!(
+                "{}/stream?streams={}",
+                url.trim_end_matches('/').trim_end_matches("/ws"),
+                subscription
+            ),
+        }
+    }
+}
+
+impl FuturesMarket {
+    fn ws_endpoint(&self, config: &Config) -> String {
+        match self {
+            FuturesMarket::USDM => config.futures_ws_endpoint.clone(),
+            FuturesMarket::COINM => config.coin_m_ws_endpoint.clone(),
+            FuturesMarket::Vanilla => config.options_ws_endpoint.clone(),
         }
     }
 }
@@ -48,8 +68,14 @@ impl FuturesWebsocketAPI {
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub enum FuturesWebsocketEvent {
-    AccountUpdate(AccountUpdateEvent),
+    AccountUpdate(model::AccountUpdateEvent),
     OrderTrade(model::OrderTradeEvent),
+    MarginCall(model::MarginCallEvent),
+    AccountConfigUpdate(model::AccountConfigUpdateEvent),
+    TradeLite(model::TradeLiteEvent),
+    StrategyUpdate(model::StrategyUpdateEvent),
+    GridUpdate(model::GridUpdateEvent),
+    ListenKeyExpired(model::ListenKeyExpiredEvent),
     AggrTrades(AggrTradesEvent),
     Trade(TradeEvent),
     OrderBook(OrderBook),
@@ -81,7 +107,6 @@ enum FuturesEvents {
     BookTickerEvent(BookTickerEvent),
     MiniTickerEvent(MiniTickerEvent),
     VecMiniTickerEvent(Vec<MiniTickerEvent>),
-    AccountUpdateEvent(AccountUpdateEvent),
     OrderTradeEvent(model::OrderTradeEvent),
     AggrTradesEvent(AggrTradesEvent),
     IndexPriceEvent(IndexPriceEvent),
@@ -112,11 +137,10 @@ impl<'a> FuturesWebSockets<'a> {
     }
 
     pub fn connect_with_config(
-        &mut self, market: FuturesMarket, subscription: &'a str, config: &'a Config,
+        &mut self, market: FuturesMarket, subscription: &str, config: &Config,
     ) -> Result<()> {
-        self.connect_wss(
-            FuturesWebsocketAPI::Custom(config.ws_endpoint.clone()).params(market, subscription),
-        )
+        let endpoint = market.ws_endpoint(config);
+        self.connect_wss(FuturesWebsocketAPI::Custom(endpoint).params(market, subscription))
     }
 
     pub fn connect_multiple_streams(
@@ -125,6 +149,15 @@ impl<'a> FuturesWebSockets<'a> {
         self.connect_wss(FuturesWebsocketAPI::MultiStream.params(market, &endpoints.join("/")))
     }
 
+    pub fn connect_multiple_streams_with_config(
+        &mut self, market: FuturesMarket, endpoints: &[String], config: &Config,
+    ) -> Result<()> {
+        let endpoint = market.ws_endpoint(config);
+        self.connect_wss(
+            FuturesWebsocketAPI::CustomMultiStream(endpoint).params(market, &endpoints.join("/")),
+        )
+    }
+
     fn connect_wss(&mut self, wss: String) -> Result<()> {
         let url = Url::parse(&wss)?;
         match connect(url) {
@@ -156,6 +189,11 @@ impl<'a> FuturesWebSockets<'a> {
             return Ok(());
         }
 
+        if let Some(event) = Self::user_data_event(&value)? {
+            (self.handler)(event)?;
+            return Ok(());
+        }
+
         if let Ok(events) = serde_json::from_value::<FuturesEvents>(value) {
             let action = match events {
                 FuturesEvents::Vec(v) => FuturesWebsocketEvent::DayTickerAll(v),
@@ -163,7 +201,6 @@ impl<'a> FuturesWebSockets<'a> {
                 FuturesEvents::BookTickerEvent(v) => FuturesWebsocketEvent::BookTicker(v),
                 FuturesEvents::MiniTickerEvent(v) => FuturesWebsocketEvent::MiniTicker(v),
                 FuturesEvents::VecMiniTickerEvent(v) => FuturesWebsocketEvent::MiniTickerAll(v),
-                FuturesEvents::AccountUpdateEvent(v) => FuturesWebsocketEvent::AccountUpdate(v),
                 FuturesEvents::OrderTradeEvent(v) => FuturesWebsocketEvent::OrderTrade(v),
                 FuturesEvents::IndexPriceEvent(v) => FuturesWebsocketEvent::IndexPrice(v),
                 FuturesEvents::MarkPriceEvent(v) => FuturesWebsocketEvent::MarkPrice(v),
@@ -182,6 +219,40 @@ impl<'a> FuturesWebSockets<'a> {
         Ok(())
     }
 
+    // User data events are told apart by their type, their payloads overlap too much
+    // to be matched by shape
+    fn user_data_event(value: &serde_json::Value) -> Result<Option<FuturesWebsocketEvent>> {
+        let event_type = match value.get("e").and_then(|e| e.as_str()) {
+            Some(event_type) => event_type,
+            None => return Ok(None),
+        };
+        let event = match event_type {
+            "ACCOUNT_UPDATE" => {
+                FuturesWebsocketEvent::AccountUpdate(model::AccountUpdateEvent::deserialize(value)?)
+            }
+            "MARGIN_CALL" => {
+                FuturesWebsocketEvent::MarginCall(model::MarginCallEvent::deserialize(value)?)
+            }
+            "ACCOUNT_CONFIG_UPDATE" => FuturesWebsocketEvent::AccountConfigUpdate(
+                model::AccountConfigUpdateEvent::deserialize(value)?,
+            ),
+            "TRADE_LITE" => {
+                FuturesWebsocketEvent::TradeLite(model::TradeLiteEvent::deserialize(value)?)
+            }
+            "STRATEGY_UPDATE" => FuturesWebsocketEvent::StrategyUpdate(
+                model::StrategyUpdateEvent::deserialize(value)?,
+            ),
+            "GRID_UPDATE" => {
+                FuturesWebsocketEvent::GridUpdate(model::GridUpdateEvent::deserialize(value)?)
+            }
+            "listenKeyExpired" => FuturesWebsocketEvent::ListenKeyExpired(
+                model::ListenKeyExpiredEvent::deserialize(value)?,
+            ),
+            _ => return Ok(None),
+        };
+        Ok(Some(event))
+    }
+
     pub fn event_loop(&mut self, running: &AtomicBool) -> Result<()> {
         while running.load(Ordering::Relaxed) {
             if let Some(ref mut socket) = self.socket {
@@ -19,9 +19,15 @@ pub mod model;
 pub mod account;
 pub mod api;
 pub mod config;
+pub mod convert;
 pub mod general;
+pub mod margin;
 pub mod market;
+pub mod options;
+pub mod preflight;
 pub mod savings;
+pub mod simple_earn;
+pub mod sub_account;
 pub mod userstream;
 pub mod websockets;
 
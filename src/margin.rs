@@ -0,0 +1,597 @@
+use crate::util::*;
+use crate::model::*;
+use crate::client::*;
+use crate::errors::*;
+use crate::preflight::{ApiPermission, RequiredPermissions};
+use std::collections::BTreeMap;
+use crate::api::API;
+use crate::api::Sapi;
+use crate::account::{OrderSide, OrderType, TimeInForce};
+
+#[derive(Clone)]
+pub struct Margin {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl RequiredPermissions for Margin {
+    fn required_permissions() -> Vec<ApiPermission> {
+        vec![
+            ApiPermission::Reading,
+            ApiPermission::SpotAndMarginTrading,
+            ApiPermission::Margin,
+        ]
+    }
+}
+
+pub enum BorrowRepayType {
+    Borrow,
+    Repay,
+}
+
+impl From<BorrowRepayType> for String {
+    fn from(item: BorrowRepayType) -> Self {
+        match item {
+            BorrowRepayType::Borrow => String::from("BORROW"),
+            BorrowRepayType::Repay => String::from("REPAY"),
+        }
+    }
+}
+
+/// Borrowing and repaying done automatically by the exchange when placing a margin order.
+pub enum SideEffectType {
+    NoSideEffect,
+    MarginBuy,
+    AutoRepay,
+    AutoBorrowRepay,
+}
+
+impl From<SideEffectType> for String {
+    fn from(item: SideEffectType) -> Self {
+        match item {
+            SideEffectType::NoSideEffect => String::from("NO_SIDE_EFFECT"),
+            SideEffectType::MarginBuy => String::from("MARGIN_BUY"),
+            SideEffectType::AutoRepay => String::from("AUTO_REPAY"),
+            SideEffectType::AutoBorrowRepay => String::from("AUTO_BORROW_REPAY"),
+        }
+    }
+}
+
+pub struct MarginOrderRequest {
+    pub symbol: String,
+    pub is_isolated: bool,
+    pub side: OrderSide,
+    pub order_type: OrderType,
+    pub qty: Option<f64>,
+    pub quote_order_qty: Option<f64>,
+    pub price: Option<f64>,
+    pub stop_price: Option<f64>,
+    pub time_in_force: Option<TimeInForce>,
+    pub new_client_order_id: Option<String>,
+    pub side_effect_type: Option<SideEffectType>,
+}
+
+/// One-Cancels-the-Other order: a LIMIT order paired with a STOP_LOSS(_LIMIT) order.
+pub struct MarginOcoOrderRequest {
+    pub symbol: String,
+    pub is_isolated: bool,
+    pub side: OrderSide,
+    pub qty: f64,
+    pub price: f64,
+    pub stop_price: f64,
+    pub stop_limit_price: Option<f64>,
+    pub stop_limit_time_in_force: Option<TimeInForce>,
+    pub list_client_order_id: Option<String>,
+    pub side_effect_type: Option<SideEffectType>,
+}
+
+impl Margin {
+    /// Borrow an asset on the cross margin account,
+    /// or on the isolated margin account of isolated_symbol.
+    pub fn borrow<S, F>(
+        &self, asset: S, amount: F, isolated_symbol: Option<String>,
+    ) -> Result<TransactionId>
+    where
+        S: Into<String>,
+        F: Into<f64>,
+    {
+        self.borrow_repay(
+            BorrowRepayType::Borrow,
+            asset.into(),
+            amount.into(),
+            isolated_symbol,
+        )
+    }
+
+    /// Repay a loan on the cross margin account,
+    /// or on the isolated margin account of isolated_symbol.
+    pub fn repay<S, F>(
+        &self, asset: S, amount: F, isolated_symbol: Option<String>,
+    ) -> Result<TransactionId>
+    where
+        S: Into<String>,
+        F: Into<f64>,
+    {
+        self.borrow_repay(
+            BorrowRepayType::Repay,
+            asset.into(),
+            amount.into(),
+            isolated_symbol,
+        )
+    }
+
+    fn borrow_repay(
+        &self, borrow_repay_type: BorrowRepayType, asset: String, amount: f64,
+        isolated_symbol: Option<String>,
+    ) -> Result<TransactionId> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("type".into(), borrow_repay_type.into());
+        parameters.insert("asset".into(), asset);
+        parameters.insert("amount".into(), amount.to_string());
+        Self::insert_isolated_symbol(&mut parameters, isolated_symbol);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::MarginBorrowRepay), request)
+    }
+
+    /// Borrow or repay records, within the last 6 months when start_time is omitted.
+    pub fn get_borrow_repay_records<S1, S2, S3>(
+        &self, borrow_repay_type: BorrowRepayType, asset: S1, isolated_symbol: Option<String>,
+        start_time: S2, end_time: S3,
+    ) -> Result<BorrowRepayRecords>
+    where
+        S1: Into<Option<String>>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("type".into(), borrow_repay_type.into());
+        if let Some(asset) = asset.into() {
+            parameters.insert("asset".into(), asset);
+        }
+        if let Some(isolated_symbol) = isolated_symbol {
+            parameters.insert("isolatedSymbol".into(), isolated_symbol);
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), st.to_string());
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), et.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::MarginBorrowRepay), Some(request))
+    }
+
+    // Cross margin account details
+    pub fn get_account(&self) -> Result<MarginAccountDetails> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::MarginAccount), Some(request))
+    }
+
+    /// Isolated margin account details, for all symbols when symbols is empty (max 5 symbols).
+    pub fn get_isolated_account<S>(&self, symbols: &[S]) -> Result<IsolatedMarginAccountDetails>
+    where
+        S: AsRef<str>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if !symbols.is_empty() {
+            let symbols: Vec<&str> = symbols.iter().map(|symbol| symbol.as_ref()).collect();
+            parameters.insert("symbols".into(), symbols.join(","));
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::MarginIsolatedAccount), Some(request))
+    }
+
+    // Enable an isolated margin account (max 10 enabled accounts)
+    pub fn enable_isolated_account<S>(&self, symbol: S) -> Result<IsolatedAccountStatus>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::MarginIsolatedAccount), request)
+    }
+
+    // Disable an isolated margin account, only allowed without pending orders or loans
+    pub fn disable_isolated_account<S>(&self, symbol: S) -> Result<IsolatedAccountStatus>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Savings(Sapi::MarginIsolatedAccount), Some(request))
+    }
+
+    // Maximum amount of an asset that can be borrowed
+    pub fn get_max_borrowable<S>(
+        &self, asset: S, isolated_symbol: Option<String>,
+    ) -> Result<MaxBorrowable>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("asset".into(), asset.into());
+        Self::insert_isolated_symbol_filter(&mut parameters, isolated_symbol);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::MarginMaxBorrowable), Some(request))
+    }
+
+    // Maximum amount of an asset that can be transferred out of the margin account
+    pub fn get_max_transferable<S>(
+        &self, asset: S, isolated_symbol: Option<String>,
+    ) -> Result<MaxTransferable>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("asset".into(), asset.into());
+        Self::insert_isolated_symbol_filter(&mut parameters, isolated_symbol);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::MarginMaxTransferable), Some(request))
+    }
+
+    /// Interest history, within the last 6 months when start_time is omitted.
+    ///
+    /// current is the page number (starting at 1) and size the page size (max 100).
+    pub fn get_interest_history<S1, S2, S3, S4, S5>(
+        &self, asset: S1, isolated_symbol: Option<String>, start_time: S2, end_time: S3,
+        current: S4, size: S5,
+    ) -> Result<InterestHistory>
+    where
+        S1: Into<Option<String>>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u32>>,
+        S5: Into<Option<u32>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(asset) = asset.into() {
+            parameters.insert("asset".into(), asset);
+        }
+        Self::insert_isolated_symbol_filter(&mut parameters, isolated_symbol);
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), st.to_string());
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), et.to_string());
+        }
+        if let Some(current) = current.into() {
+            parameters.insert("current".into(), current.to_string());
+        }
+        if let Some(size) = size.into() {
+            parameters.insert("size".into(), size.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::MarginInterestHistory), Some(request))
+    }
+
+    // Place a margin order
+    pub fn new_order(&self, order: MarginOrderRequest) -> Result<MarginTransaction> {
+        let order = self.build_order(order);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::MarginOrder), request)
+    }
+
+    // Check a margin order's status
+    pub fn order_status<S>(
+        &self, symbol: S, order_id: u64, is_isolated: bool,
+    ) -> Result<MarginOrder>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+        Self::insert_is_isolated(&mut parameters, is_isolated);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::MarginOrder), Some(request))
+    }
+
+    // Cancel a margin order
+    pub fn cancel_order<S>(
+        &self, symbol: S, order_id: u64, is_isolated: bool,
+    ) -> Result<OrderCanceled>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+        Self::insert_is_isolated(&mut parameters, is_isolated);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Savings(Sapi::MarginOrder), Some(request))
+    }
+
+    // Current open margin orders for ONE symbol
+    pub fn get_open_orders<S>(&self, symbol: S, is_isolated: bool) -> Result<Vec<MarginOrder>>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        Self::insert_is_isolated(&mut parameters, is_isolated);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::MarginOpenOrders), Some(request))
+    }
+
+    // Cancel all open margin orders (including OCO orders) for ONE symbol
+    pub fn cancel_all_open_orders<S>(
+        &self, symbol: S, is_isolated: bool,
+    ) -> Result<Vec<OrderCanceled>>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        Self::insert_is_isolated(&mut parameters, is_isolated);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Savings(Sapi::MarginOpenOrders), Some(request))
+    }
+
+    /// All margin orders for ONE symbol: active, canceled, or filled.
+    ///
+    /// If order_id is set, orders >= order_id are returned. Otherwise the most recent orders are returned.
+    pub fn get_all_orders<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, is_isolated: bool, order_id: S2, start_time: S3, end_time: S4, limit: S5,
+    ) -> Result<Vec<MarginOrder>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        Self::insert_is_isolated(&mut parameters, is_isolated);
+        if let Some(oi) = order_id.into() {
+            parameters.insert("orderId".into(), oi.to_string());
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), st.to_string());
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), et.to_string());
+        }
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), lt.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::MarginAllOrders), Some(request))
+    }
+
+    // Place a margin OCO order
+    pub fn new_oco_order(&self, order: MarginOcoOrderRequest) -> Result<MarginOrderList> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), order.symbol);
+        Self::insert_is_isolated(&mut parameters, order.is_isolated);
+        parameters.insert("side".into(), order.side.into());
+        parameters.insert("quantity".into(), order.qty.to_string());
+        parameters.insert("price".into(), order.price.to_string());
+        parameters.insert("stopPrice".into(), order.stop_price.to_string());
+        if let Some(stop_limit_price) = order.stop_limit_price {
+            parameters.insert("stopLimitPrice".into(), stop_limit_price.to_string());
+        }
+        if let Some(time_in_force) = order.stop_limit_time_in_force {
+            parameters.insert("stopLimitTimeInForce".into(), time_in_force.into());
+        }
+        if let Some(list_client_order_id) = order.list_client_order_id {
+            parameters.insert("listClientOrderId".into(), list_client_order_id);
+        }
+        if let Some(side_effect_type) = order.side_effect_type {
+            parameters.insert("sideEffectType".into(), side_effect_type.into());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::MarginOco), request)
+    }
+
+    // Cancel a margin OCO order
+    pub fn cancel_oco_order<S>(
+        &self, symbol: S, order_list_id: u64, is_isolated: bool,
+    ) -> Result<MarginOrderList>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderListId".into(), order_list_id.to_string());
+        Self::insert_is_isolated(&mut parameters, is_isolated);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Savings(Sapi::MarginOrderList), Some(request))
+    }
+
+    // Check a margin OCO order's status, symbol is mandatory for isolated margin
+    pub fn get_oco_order(
+        &self, order_list_id: u64, symbol: Option<String>, is_isolated: bool,
+    ) -> Result<MarginOrderList> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("orderListId".into(), order_list_id.to_string());
+        if let Some(symbol) = symbol {
+            parameters.insert("symbol".into(), symbol);
+        }
+        Self::insert_is_isolated(&mut parameters, is_isolated);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::MarginOrderList), Some(request))
+    }
+
+    /// Margin trades for ONE symbol.
+    ///
+    /// If from_id is set, trades >= from_id are returned. Otherwise the most recent trades are returned.
+    pub fn trade_history<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, is_isolated: bool, from_id: S2, start_time: S3, end_time: S4, limit: S5,
+    ) -> Result<Vec<MarginTrade>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        Self::insert_is_isolated(&mut parameters, is_isolated);
+        if let Some(fi) = from_id.into() {
+            parameters.insert("fromId".into(), fi.to_string());
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), st.to_string());
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), et.to_string());
+        }
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), lt.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::MarginMyTrades), Some(request))
+    }
+
+    // Cross margin user stream
+    pub fn start_user_data_stream(&self) -> Result<UserDataStream> {
+        self.client.post(API::Savings(Sapi::MarginUserDataStream))
+    }
+
+    pub fn keep_alive_user_data_stream(&self, listen_key: &str) -> Result<Success> {
+        self.client
+            .put(API::Savings(Sapi::MarginUserDataStream), listen_key)
+    }
+
+    pub fn close_user_data_stream(&self, listen_key: &str) -> Result<Success> {
+        self.client
+            .delete(API::Savings(Sapi::MarginUserDataStream), listen_key)
+    }
+
+    // Isolated margin user stream, one listen key per symbol
+    pub fn start_isolated_user_data_stream<S>(&self, symbol: S) -> Result<UserDataStream>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+
+        let request = build_request(parameters);
+        self.client
+            .post_with_api_key(API::Savings(Sapi::IsolatedMarginUserDataStream), request)
+    }
+
+    pub fn keep_alive_isolated_user_data_stream<S>(
+        &self, symbol: S, listen_key: &str,
+    ) -> Result<Success>
+    where
+        S: Into<String>,
+    {
+        let request = Self::isolated_stream_request(symbol.into(), listen_key);
+        self.client
+            .put_with_api_key(API::Savings(Sapi::IsolatedMarginUserDataStream), request)
+    }
+
+    pub fn close_isolated_user_data_stream<S>(&self, symbol: S, listen_key: &str) -> Result<Success>
+    where
+        S: Into<String>,
+    {
+        let request = Self::isolated_stream_request(symbol.into(), listen_key);
+        self.client
+            .delete_with_api_key(API::Savings(Sapi::IsolatedMarginUserDataStream), request)
+    }
+
+    fn isolated_stream_request(symbol: String, listen_key: &str) -> String {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("listenKey".into(), listen_key.into());
+        parameters.insert("symbol".into(), symbol);
+        build_request(parameters)
+    }
+
+    fn build_order(&self, order: MarginOrderRequest) -> BTreeMap<String, String> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), order.symbol);
+        Self::insert_is_isolated(&mut parameters, order.is_isolated);
+        parameters.insert("side".into(), order.side.into());
+        parameters.insert("type".into(), order.order_type.into());
+
+        if let Some(qty) = order.qty {
+            parameters.insert("quantity".into(), qty.to_string());
+        }
+        if let Some(quote_order_qty) = order.quote_order_qty {
+            parameters.insert("quoteOrderQty".into(), quote_order_qty.to_string());
+        }
+        if let Some(price) = order.price {
+            parameters.insert("price".into(), price.to_string());
+        }
+        if let Some(stop_price) = order.stop_price {
+            parameters.insert("stopPrice".into(), stop_price.to_string());
+        }
+        if let Some(time_in_force) = order.time_in_force {
+            parameters.insert("timeInForce".into(), time_in_force.into());
+        }
+        if let Some(client_order_id) = order.new_client_order_id {
+            parameters.insert("newClientOrderId".into(), client_order_id);
+        }
+        if let Some(side_effect_type) = order.side_effect_type {
+            parameters.insert("sideEffectType".into(), side_effect_type.into());
+        }
+
+        parameters
+    }
+
+    // Borrow and repay take isIsolated=TRUE plus the symbol
+    fn insert_isolated_symbol(
+        parameters: &mut BTreeMap<String, String>, isolated_symbol: Option<String>,
+    ) {
+        if let Some(isolated_symbol) = isolated_symbol {
+            parameters.insert("isIsolated".into(), "TRUE".into());
+            parameters.insert("symbol".into(), isolated_symbol);
+        }
+    }
+
+    // Queries take the symbol as isolatedSymbol
+    fn insert_isolated_symbol_filter(
+        parameters: &mut BTreeMap<String, String>, isolated_symbol: Option<String>,
+    ) {
+        if let Some(isolated_symbol) = isolated_symbol {
+            parameters.insert("isolatedSymbol".into(), isolated_symbol);
+        }
+    }
+
+    fn insert_is_isolated(parameters: &mut BTreeMap<String, String>, is_isolated: bool) {
+        if is_isolated {
+            parameters.insert("isIsolated".into(), "TRUE".into());
+        }
+    }
+}
@@ -8,12 +8,31 @@ use crate::api::API;
 use crate::api::Spot;
 use std::convert::TryInto;
 
+// Maximum number of trades returned by a single trades request
+const TRADES_PAGE_LIMIT: u16 = 1000;
+
 #[derive(Clone)]
 pub struct Market {
     pub client: Client,
     pub recv_window: u64,
 }
 
+/// Shape of the ticker statistics: FULL includes the price change fields, MINI omits them.
+#[derive(Clone, Copy, Debug)]
+pub enum TickerType {
+    Full,
+    Mini,
+}
+
+impl From<TickerType> for String {
+    fn from(item: TickerType) -> Self {
+        match item {
+            TickerType::Full => String::from("FULL"),
+            TickerType::Mini => String::from("MINI"),
+        }
+    }
+}
+
 // Market Data endpoints
 impl Market {
     // Order book at the default depth of 100
@@ -56,6 +75,17 @@ impl Market {
         self.client.get(API::Spot(Spot::Price), Some(request))
     }
 
+    // Latest price for SEVERAL symbols.
+    pub fn get_prices<S>(&self, symbols: &[S]) -> Result<Vec<SymbolPrice>>
+    where
+        S: AsRef<str>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbols".into(), build_symbols_param(symbols));
+        let request = build_request(parameters);
+        self.client.get(API::Spot(Spot::Price), Some(request))
+    }
+
     // Average price for ONE symbol.
     pub fn get_average_price<S>(&self, symbol: S) -> Result<AveragePrice>
     where
@@ -84,6 +114,17 @@ impl Market {
         self.client.get(API::Spot(Spot::BookTicker), Some(request))
     }
 
+    // -> Best price/qty on the order book for SEVERAL symbols
+    pub fn get_book_tickers<S>(&self, symbols: &[S]) -> Result<Vec<Tickers>>
+    where
+        S: AsRef<str>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbols".into(), build_symbols_param(symbols));
+        let request = build_request(parameters);
+        self.client.get(API::Spot(Spot::BookTicker), Some(request))
+    }
+
     // 24hr ticker price change statistics
     pub fn get_24h_price_stats<S>(&self, symbol: S) -> Result<PriceStats>
     where
@@ -95,14 +136,172 @@ impl Market {
         self.client.get(API::Spot(Spot::Ticker24hr), Some(request))
     }
 
+    // 24hr ticker price change statistics for SEVERAL symbols
+    pub fn get_24h_price_stats_for_symbols<S>(&self, symbols: &[S]) -> Result<Vec<PriceStats>>
+    where
+        S: AsRef<str>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbols".into(), build_symbols_param(symbols));
+        let request = build_request(parameters);
+        self.client.get(API::Spot(Spot::Ticker24hr), Some(request))
+    }
+
     // 24hr ticker price change statistics for all symbols
     pub fn get_all_24h_price_stats(&self) -> Result<Vec<PriceStats>> {
         self.client.get(API::Spot(Spot::Ticker24hr), None)
     }
 
+    /// Rolling window price change statistics for ONE symbol.
+    ///
+    /// window_size is 1m to 59m, 1h to 23h or 1d to 7d (defaults to 1d).
+    pub fn get_rolling_window_price_stats<S1>(
+        &self, symbol: S1, window_size: Option<&str>, ticker_type: TickerType,
+    ) -> Result<TickerStatistics>
+    where
+        S1: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("type".into(), ticker_type.into());
+        if let Some(ws) = window_size {
+            parameters.insert("windowSize".into(), ws.into());
+        }
+        let request = build_request(parameters);
+        self.client.get(API::Spot(Spot::Ticker), Some(request))
+    }
+
+    /// Rolling window price change statistics for SEVERAL symbols (max 100).
+    pub fn get_rolling_window_price_stats_for_symbols<S1>(
+        &self, symbols: &[S1], window_size: Option<&str>, ticker_type: TickerType,
+    ) -> Result<Vec<TickerStatistics>>
+    where
+        S1: AsRef<str>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbols".into(), build_symbols_param(symbols));
+        parameters.insert("type".into(), ticker_type.into());
+        if let Some(ws) = window_size {
+            parameters.insert("windowSize".into(), ws.into());
+        }
+        let request = build_request(parameters);
+        self.client.get(API::Spot(Spot::Ticker), Some(request))
+    }
+
+    /// Price change statistics for the current trading day of ONE symbol.
+    ///
+    /// time_zone is an hours offset such as "-1:00" or "05:45" (defaults to "0", UTC).
+    pub fn get_trading_day_price_stats<S1>(
+        &self, symbol: S1, time_zone: Option<&str>, ticker_type: TickerType,
+    ) -> Result<TickerStatistics>
+    where
+        S1: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("type".into(), ticker_type.into());
+        if let Some(tz) = time_zone {
+            parameters.insert("timeZone".into(), tz.into());
+        }
+        let request = build_request(parameters);
+        self.client
+            .get(API::Spot(Spot::TickerTradingDay), Some(request))
+    }
+
+    /// Price change statistics for the current trading day of SEVERAL symbols (max 100).
+    pub fn get_trading_day_price_stats_for_symbols<S1>(
+        &self, symbols: &[S1], time_zone: Option<&str>, ticker_type: TickerType,
+    ) -> Result<Vec<TickerStatistics>>
+    where
+        S1: AsRef<str>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbols".into(), build_symbols_param(symbols));
+        parameters.insert("type".into(), ticker_type.into());
+        if let Some(tz) = time_zone {
+            parameters.insert("timeZone".into(), tz.into());
+        }
+        let request = build_request(parameters);
+        self.client
+            .get(API::Spot(Spot::TickerTradingDay), Some(request))
+    }
+
+    /// Get recent trades.
+    ///
+    /// If limit is omitted, the last 500 trades are fetched (max 1000).
+    pub fn get_trades<S1, S2>(&self, symbol: S1, limit: S2) -> Result<Vec<Trade>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), format!("{}", lt));
+        }
+        let request = build_request(parameters);
+        self.client.get(API::Spot(Spot::Trades), Some(request))
+    }
+
+    /// Get older trades, starting at from_id.
+    ///
+    /// Requires an API key, but the request is not signed.
+    /// If from_id is omitted, the most recent trades are fetched.
+    pub fn get_historical_trades<S1, S2, S3>(
+        &self, symbol: S1, from_id: S2, limit: S3,
+    ) -> Result<Vec<Trade>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), format!("{}", lt));
+        }
+        if let Some(fi) = from_id.into() {
+            parameters.insert("fromId".into(), format!("{}", fi));
+        }
+        let request = build_request(parameters);
+        self.client
+            .get_with_api_key(API::Spot(Spot::HistoricalTrades), Some(request))
+    }
+
+    /// Iterate over all trades with an id between from_id and to_id (both inclusive).
+    ///
+    /// Trades are fetched lazily, one page of historical trades at a time.
+    pub fn backfill_trades_by_id<S>(&self, symbol: S, from_id: u64, to_id: u64) -> TradeBackfill<'_>
+    where
+        S: Into<String>,
+    {
+        Pages::new(TradePages::new(
+            self,
+            symbol.into(),
+            Some(from_id),
+            Some(to_id),
+            None,
+        ))
+    }
+
+    /// Iterate over all trades executed between start_time and end_time (both inclusive).
+    ///
+    /// The first page looks up the id of the first trade with the aggregated trades endpoint,
+    /// then trades are fetched lazily, one page of historical trades at a time.
+    pub fn backfill_trades_by_time<S>(
+        &self, symbol: S, start_time: u64, end_time: u64,
+    ) -> TradeBackfill<'_>
+    where
+        S: Into<String>,
+    {
+        let mut trades = TradePages::new(self, symbol.into(), None, None, Some(end_time));
+        trades.start_time = Some(start_time);
+        Pages::new(trades)
+    }
+
     /// Get aggregated historical trades.
     ///
-    /// If you provide start_time, you also need to provide end_time.
+    /// If you provide both start_time and end_time, they must be less than an hour apart.
     /// If from_id, start_time and end_time are omitted, the most recent trades are fetched.
     pub fn get_agg_trades<S1, S2, S3, S4, S5>(
         &self, symbol: S1, from_id: S2, start_time: S3, end_time: S4, limit: S5,
@@ -165,8 +364,47 @@ impl Market {
             parameters.insert("endTime".into(), format!("{}", et));
         }
 
+        self.klines(Spot::Klines, parameters)
+    }
+
+    /// Returns up to 'limit' klines optimized for presentation in candlestick charts.
+    ///
+    /// Same parameters as `get_klines`, plus an optional time_zone for the kline intervals.
+    pub fn get_ui_klines<S1, S2, S3, S4, S5, S6>(
+        &self, symbol: S1, interval: S2, limit: S3, start_time: S4, end_time: S5, time_zone: S6,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+        S6: Into<Option<String>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("interval".into(), interval.into());
+
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), format!("{}", lt));
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), format!("{}", st));
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), format!("{}", et));
+        }
+        if let Some(tz) = time_zone.into() {
+            parameters.insert("timeZone".into(), tz);
+        }
+
+        self.klines(Spot::UiKlines, parameters)
+    }
+
+    fn klines(&self, route: Spot, parameters: BTreeMap<String, String>) -> Result<KlineSummaries> {
         let request = build_request(parameters);
-        let data: Vec<Vec<Value>> = self.client.get(API::Spot(Spot::Klines), Some(request))?;
+        let data: Vec<Vec<Value>> = self.client.get(API::Spot(route), Some(request))?;
 
         let klines = KlineSummaries::AllKlineSummaries(
             data.iter()
@@ -177,3 +415,89 @@ impl Market {
         Ok(klines)
     }
 }
+
+/// Iterator over historical trades, see `Market::backfill_trades_by_id`
+/// and `Market::backfill_trades_by_time`.
+pub type TradeBackfill<'a> = Pages<TradePages<'a>>;
+
+/// Pages of a `TradeBackfill`, each one starts after the id of the last trade read.
+pub struct TradePages<'a> {
+    market: &'a Market,
+    symbol: String,
+    from_id: Option<u64>,
+    to_id: Option<u64>,
+    start_time: Option<u64>,
+    end_time: Option<u64>,
+    finished: bool,
+}
+
+impl<'a> TradePages<'a> {
+    fn new(
+        market: &'a Market, symbol: String, from_id: Option<u64>, to_id: Option<u64>,
+        end_time: Option<u64>,
+    ) -> Self {
+        TradePages {
+            market,
+            symbol,
+            from_id,
+            to_id,
+            start_time: None,
+            end_time,
+            finished: false,
+        }
+    }
+}
+
+impl<'a> PageSource for TradePages<'a> {
+    type Item = Trade;
+
+    fn fetch_page(&mut self) -> Result<Vec<Trade>> {
+        // Trades backfilled by time start at the first trade at or after start_time
+        if let Some(start_time) = self.start_time {
+            let agg_trades =
+                self.market
+                    .get_agg_trades(self.symbol.clone(), None, start_time, None, 1)?;
+            let end_time = self.end_time;
+            let first_trade = agg_trades
+                .first()
+                .filter(|trade| !matches!(end_time, Some(end_time) if trade.time > end_time));
+            self.start_time = None;
+            match first_trade {
+                Some(trade) => self.from_id = Some(trade.first_id),
+                None => {
+                    self.finished = true;
+                    return Ok(Vec::new());
+                }
+            }
+        }
+
+        let trades = self.market.get_historical_trades(
+            self.symbol.clone(),
+            self.from_id,
+            TRADES_PAGE_LIMIT,
+        )?;
+
+        // A short page means we caught up with the most recent trade
+        if trades.len() < usize::from(TRADES_PAGE_LIMIT) {
+            self.finished = true;
+        }
+
+        let mut page = Vec::with_capacity(trades.len());
+        for trade in trades {
+            let past_id = matches!(self.to_id, Some(to_id) if trade.id > to_id);
+            let past_time = matches!(self.end_time, Some(end_time) if trade.time > end_time);
+            if past_id || past_time {
+                self.finished = true;
+                break;
+            }
+            self.from_id = Some(trade.id + 1);
+            page.push(trade);
+        }
+
+        Ok(page)
+    }
+
+    fn finished(&self) -> bool {
+        self.finished
+    }
+}
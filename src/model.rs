@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use serde_json::{from_value, Value};
+use std::collections::BTreeMap;
 use std::convert::TryFrom;
 use crate::errors::*;
 
@@ -120,9 +121,97 @@ pub struct AccountInformation {
     pub can_trade: bool,
     pub can_withdraw: bool,
     pub can_deposit: bool,
+    #[serde(default)]
+    pub commission_rates: Option<CommissionRates>,
     pub balances: Vec<Balance>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CommissionRates {
+    #[serde(with = "string_or_float")]
+    pub maker: f64,
+    #[serde(with = "string_or_float")]
+    pub taker: f64,
+    #[serde(with = "string_or_float")]
+    pub buyer: f64,
+    #[serde(with = "string_or_float")]
+    pub seller: f64,
+}
+
+/// Commission rates of the account for a symbol (endpoint /api/v3/account/commission).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountCommission {
+    pub symbol: String,
+    pub standard_commission: CommissionRates,
+    pub tax_commission: CommissionRates,
+    pub discount: CommissionDiscount,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CommissionDiscount {
+    pub enabled_for_account: bool,
+    pub enabled_for_symbol: bool,
+    pub discount_asset: String,
+    #[serde(with = "string_or_float")]
+    pub discount: f64,
+}
+
+/// Current unfilled order count for one order rate limit interval.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UnfilledOrderCount {
+    pub rate_limit_type: String,
+    pub interval: String,
+    pub interval_num: u16,
+    pub limit: u64,
+    pub count: u64,
+}
+
+/// An order that expired because of Self-Trade Prevention (STP).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PreventedMatch {
+    pub symbol: String,
+    pub prevented_match_id: u64,
+    pub taker_order_id: u64,
+    pub maker_symbol: String,
+    pub maker_order_id: u64,
+    pub trade_group_id: i64,
+    pub self_trade_prevention_mode: String,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub maker_prevented_quantity: f64,
+    pub transact_time: u64,
+}
+
+/// A fill resulting from Smart Order Routing (SOR).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Allocation {
+    pub symbol: String,
+    pub allocation_id: u64,
+    pub allocation_type: String,
+    pub order_id: u64,
+    pub order_list_id: i64,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub qty: f64,
+    #[serde(with = "string_or_float")]
+    pub quote_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub commission: f64,
+    pub commission_asset: String,
+    pub time: u64,
+    pub is_buyer: bool,
+    pub is_maker: bool,
+    pub is_allocator: bool,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Balance {
@@ -190,6 +279,11 @@ pub struct Transaction {
     pub type_name: String,
     pub side: String,
     pub fills: Option<Vec<FillInfo>>,
+    /// Set on orders placed with Smart Order Routing (SOR).
+    #[serde(default)]
+    pub working_floor: Option<String>,
+    #[serde(default)]
+    pub used_sor: Option<bool>,
 }
 
 fn default_stop_price() -> f64 {
@@ -206,8 +300,24 @@ pub struct FillInfo {
     #[serde(with = "string_or_float")]
     pub commission: f64,
     pub commission_asset: String,
+    /// None for fills allocated by Smart Order Routing (SOR), which report a trade id of -1
+    #[serde(default, deserialize_with = "sor_trade_id")]
     pub trade_id: Option<u64>,
+    /// Allocation id of the fill, only for orders placed with Smart Order Routing (SOR).
+    #[serde(default)]
+    pub alloc_id: Option<u64>,
+    #[serde(default)]
+    pub match_type: Option<String>,
+}
+
+fn sor_trade_id<'de, D>(deserializer: D) -> std::result::Result<Option<u64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let trade_id: Option<i64> = Option::deserialize(deserializer)?;
+    Ok(trade_id.and_then(|trade_id| u64::try_from(trade_id).ok()))
 }
+
 /// Response to a test order (endpoint /api/v3/order/test).
 ///
 /// Currently, the API responds {} on a successfull test transaction,
@@ -348,6 +458,82 @@ pub struct PriceStats {
     pub count: u64,
 }
 
+/// Response of the rolling window and trading day tickers,
+/// depending on the requested `TickerType`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum TickerStatistics {
+    Full(FullTickerStatistics),
+    Mini(MiniTickerStatistics),
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FullTickerStatistics {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub price_change: f64,
+    #[serde(with = "string_or_float")]
+    pub price_change_percent: f64,
+    #[serde(with = "string_or_float")]
+    pub weighted_avg_price: f64,
+    #[serde(with = "string_or_float")]
+    pub open_price: f64,
+    #[serde(with = "string_or_float")]
+    pub high_price: f64,
+    #[serde(with = "string_or_float")]
+    pub low_price: f64,
+    #[serde(with = "string_or_float")]
+    pub last_price: f64,
+    #[serde(with = "string_or_float")]
+    pub volume: f64,
+    #[serde(with = "string_or_float")]
+    pub quote_volume: f64,
+    pub open_time: u64,
+    pub close_time: u64,
+    pub first_id: i64,
+    pub last_id: i64,
+    pub count: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MiniTickerStatistics {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub open_price: f64,
+    #[serde(with = "string_or_float")]
+    pub high_price: f64,
+    #[serde(with = "string_or_float")]
+    pub low_price: f64,
+    #[serde(with = "string_or_float")]
+    pub last_price: f64,
+    #[serde(with = "string_or_float")]
+    pub volume: f64,
+    #[serde(with = "string_or_float")]
+    pub quote_volume: f64,
+    pub open_time: u64,
+    pub close_time: u64,
+    pub first_id: i64,
+    pub last_id: i64,
+    pub count: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade {
+    pub id: u64,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub qty: f64,
+    #[serde(with = "string_or_float")]
+    pub quote_qty: f64,
+    pub time: u64,
+    pub is_buyer_maker: bool,
+    pub is_best_match: bool,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct AggTrade {
     #[serde(rename = "T")]
@@ -900,7 +1086,24 @@ pub struct KlineSummary {
     pub taker_buy_quote_asset_volume: String,
 }
 
-fn get_value(row: &[Value], index: usize, name: &'static str) -> Result<Value> {
+// Item of a batch response, an error is returned in place of a rejected order
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(crate) enum BatchResult<T> {
+    Error(BinanceContentError),
+    Success(T),
+}
+
+impl<T> BatchResult<T> {
+    pub(crate) fn into_result(self) -> std::result::Result<T, BinanceContentError> {
+        match self {
+            BatchResult::Error(error) => Err(error),
+            BatchResult::Success(item) => Ok(item),
+        }
+    }
+}
+
+pub(crate) fn get_value(row: &[Value], index: usize, name: &'static str) -> Result<Value> {
     Ok(row
         .get(index)
         .ok_or(ErrorKind::KlineValueMissingError(index, name))?
@@ -1127,109 +1330,1364 @@ pub struct DepthOrderBookEvent {
     pub asks: Vec<Asks>,
 }
 
-/// Response to the Savings API get all coins request
+/// Response to a margin borrow or repay request
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct CoinInfo {
-    pub coin: String,
-    pub deposit_all_enable: bool,
+pub struct TransactionId {
+    pub tran_id: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BorrowRepayRecords {
+    pub rows: Vec<BorrowRepayRecord>,
+    pub total: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BorrowRepayRecord {
+    #[serde(default)]
+    pub isolated_symbol: Option<String>,
     #[serde(with = "string_or_float")]
-    pub free: f64,
+    pub amount: f64,
+    pub asset: String,
     #[serde(with = "string_or_float")]
-    pub freeze: f64,
+    pub interest: f64,
     #[serde(with = "string_or_float")]
-    pub ipoable: f64,
+    pub principal: f64,
+    pub status: String,
+    pub timestamp: u64,
+    pub tx_id: u64,
+}
+
+/// Cross margin account details
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginAccountDetails {
+    pub borrow_enabled: bool,
     #[serde(with = "string_or_float")]
-    pub ipoing: f64,
-    pub is_legal_money: bool,
+    pub margin_level: f64,
     #[serde(with = "string_or_float")]
-    pub locked: f64,
-    pub name: String,
-    pub network_list: Vec<Network>,
+    pub total_asset_of_btc: f64,
     #[serde(with = "string_or_float")]
-    pub storage: f64,
-    pub trading: bool,
-    pub withdraw_all_enable: bool,
+    pub total_liability_of_btc: f64,
     #[serde(with = "string_or_float")]
-    pub withdrawing: f64,
+    pub total_net_asset_of_btc: f64,
+    pub trade_enabled: bool,
+    pub transfer_enabled: bool,
+    pub user_assets: Vec<UserAsset>,
 }
 
-/// Part of the Savings API get all coins response
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct Network {
-    pub address_regex: String,
-    pub coin: String,
-    /// shown only when "depositEnable" is false.
-    pub deposit_desc: Option<String>,
-    pub deposit_enable: bool,
-    pub is_default: bool,
-    pub memo_regex: String,
-    /// min number for balance confirmation
-    pub min_confirm: u32,
-    pub name: String,
-    pub network: String,
-    pub reset_address_status: bool,
-    pub special_tips: Option<String>,
-    /// confirmation number for balance unlock
-    pub un_lock_confirm: u32,
-    /// shown only when "withdrawEnable" is false.
-    pub withdraw_desc: Option<String>,
-    pub withdraw_enable: bool,
+pub struct UserAsset {
+    pub asset: String,
     #[serde(with = "string_or_float")]
-    pub withdraw_fee: f64,
+    pub borrowed: f64,
     #[serde(with = "string_or_float")]
-    pub withdraw_min: f64,
-    // pub insert_time: Option<u64>, //commented out for now, because they are not inside the actual response (only the api doc example)
-    // pub update_time: Option<u64>,
-    pub withdraw_integer_multiple: Option<String>,
+    pub free: f64,
+    #[serde(with = "string_or_float")]
+    pub interest: f64,
+    #[serde(with = "string_or_float")]
+    pub locked: f64,
+    #[serde(with = "string_or_float")]
+    pub net_asset: f64,
 }
 
+/// Isolated margin account details
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct AssetDetail {
+pub struct IsolatedMarginAccountDetails {
+    pub assets: Vec<IsolatedMarginAccountAsset>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub total_asset_of_btc: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub total_liability_of_btc: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub total_net_asset_of_btc: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginAccountAsset {
+    pub base_asset: IsolatedAssetDetails,
+    pub quote_asset: IsolatedAssetDetails,
+    pub symbol: String,
+    pub isolated_created: bool,
+    pub enabled: bool,
     #[serde(with = "string_or_float")]
-    pub min_withdraw_amount: f64,
-    /// false if ALL of networks' are false
-    pub deposit_status: bool,
+    pub margin_level: f64,
+    pub margin_level_status: String,
     #[serde(with = "string_or_float")]
-    pub withdraw_fee: f64,
-    /// false if ALL of networks' are false
-    pub withdraw_status: bool,
-    /// reason
-    pub deposit_tip: Option<String>,
+    pub margin_ratio: f64,
+    #[serde(with = "string_or_float")]
+    pub index_price: f64,
+    #[serde(with = "string_or_float")]
+    pub liquidate_price: f64,
+    #[serde(with = "string_or_float")]
+    pub liquidate_rate: f64,
+    pub trade_enabled: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct DepositAddress {
-    pub address: String,
-    pub coin: String,
-    pub tag: String,
-    pub url: String,
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedAssetDetails {
+    pub asset: String,
+    pub borrow_enabled: bool,
+    #[serde(with = "string_or_float")]
+    pub borrowed: f64,
+    #[serde(with = "string_or_float")]
+    pub free: f64,
+    #[serde(with = "string_or_float")]
+    pub interest: f64,
+    #[serde(with = "string_or_float")]
+    pub locked: f64,
+    #[serde(with = "string_or_float")]
+    pub net_asset: f64,
+    #[serde(with = "string_or_float")]
+    pub net_asset_of_btc: f64,
+    pub repay_enabled: bool,
+    #[serde(with = "string_or_float")]
+    pub total_asset: f64,
 }
 
-pub(crate) mod string_or_float {
-    use std::fmt;
-
-    use serde::{de, Serializer, Deserialize, Deserializer};
-
-    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        T: fmt::Display,
-        S: Serializer,
-    {
-        serializer.collect_str(value)
-    }
+/// Response to a new margin order
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginTransaction {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+    pub transact_time: u64,
+    #[serde(default, with = "string_or_float_opt")]
+    pub price: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub orig_qty: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub executed_qty: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub cummulative_quote_qty: Option<f64>,
+    pub status: Option<String>,
+    pub time_in_force: Option<String>,
+    #[serde(rename = "type")]
+    pub type_name: Option<String>,
+    pub side: Option<String>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub margin_buy_borrow_amount: Option<f64>,
+    pub margin_buy_borrow_asset: Option<String>,
+    pub is_isolated: bool,
+    pub fills: Option<Vec<FillInfo>>,
+}
 
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<f64, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        #[derive(Deserialize)]
-        #[serde(untagged)]
-        enum StringOrFloat {
-            String(String),
-            Float(f64),
+/// State of a margin order
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginOrder {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub orig_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub cummulative_quote_qty: f64,
+    pub status: String,
+    pub time_in_force: String,
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub side: String,
+    #[serde(with = "string_or_float", default = "default_stop_price")]
+    pub stop_price: f64,
+    #[serde(with = "string_or_float", default = "default_stop_price")]
+    pub iceberg_qty: f64,
+    pub time: u64,
+    pub update_time: u64,
+    pub is_working: bool,
+    pub is_isolated: bool,
+}
+
+/// OCO order list placed, canceled or queried on a margin account
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginOrderList {
+    pub order_list_id: u64,
+    pub contingency_type: String,
+    pub list_status_type: String,
+    pub list_order_status: String,
+    pub list_client_order_id: String,
+    pub transaction_time: u64,
+    pub symbol: String,
+    #[serde(default)]
+    pub is_isolated: Option<bool>,
+    pub orders: Vec<OrderListEntry>,
+    #[serde(default)]
+    pub order_reports: Vec<OrderListReport>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderListEntry {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderListReport {
+    pub symbol: String,
+    pub order_id: u64,
+    pub order_list_id: i64,
+    pub client_order_id: String,
+    #[serde(default)]
+    pub orig_client_order_id: Option<String>,
+    #[serde(default)]
+    pub transact_time: Option<u64>,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub orig_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub cummulative_quote_qty: f64,
+    pub status: String,
+    pub time_in_force: String,
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub side: String,
+    #[serde(with = "string_or_float", default = "default_stop_price")]
+    pub stop_price: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginTrade {
+    pub id: u64,
+    pub symbol: String,
+    pub order_id: u64,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub qty: f64,
+    #[serde(with = "string_or_float")]
+    pub commission: f64,
+    pub commission_asset: String,
+    pub time: u64,
+    pub is_buyer: bool,
+    pub is_maker: bool,
+    pub is_best_match: bool,
+    pub is_isolated: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MaxBorrowable {
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    #[serde(with = "string_or_float")]
+    pub borrow_limit: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MaxTransferable {
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InterestHistory {
+    pub rows: Vec<InterestHistoryEntry>,
+    pub total: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InterestHistoryEntry {
+    pub tx_id: u64,
+    /// Spelling as returned by the API
+    pub interest_accured_time: u64,
+    pub asset: String,
+    #[serde(default)]
+    pub raw_asset: Option<String>,
+    #[serde(with = "string_or_float")]
+    pub principal: f64,
+    #[serde(with = "string_or_float")]
+    pub interest: f64,
+    #[serde(with = "string_or_float")]
+    pub interest_rate: f64,
+    #[serde(rename = "type")]
+    pub interest_type: String,
+    #[serde(default)]
+    pub isolated_symbol: Option<String>,
+}
+
+/// Response to enabling or disabling an isolated margin account
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedAccountStatus {
+    pub success: bool,
+    pub symbol: String,
+}
+
+/// Response to the Savings API get all coins request
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinInfo {
+    pub coin: String,
+    pub deposit_all_enable: bool,
+    #[serde(with = "string_or_float")]
+    pub free: f64,
+    #[serde(with = "string_or_float")]
+    pub freeze: f64,
+    #[serde(with = "string_or_float")]
+    pub ipoable: f64,
+    #[serde(with = "string_or_float")]
+    pub ipoing: f64,
+    pub is_legal_money: bool,
+    #[serde(with = "string_or_float")]
+    pub locked: f64,
+    pub name: String,
+    pub network_list: Vec<Network>,
+    #[serde(with = "string_or_float")]
+    pub storage: f64,
+    pub trading: bool,
+    pub withdraw_all_enable: bool,
+    #[serde(with = "string_or_float")]
+    pub withdrawing: f64,
+}
+
+/// Part of the Savings API get all coins response
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Network {
+    pub address_regex: String,
+    pub coin: String,
+    /// shown only when "depositEnable" is false.
+    pub deposit_desc: Option<String>,
+    pub deposit_enable: bool,
+    pub is_default: bool,
+    pub memo_regex: String,
+    /// min number for balance confirmation
+    pub min_confirm: u32,
+    pub name: String,
+    pub network: String,
+    pub reset_address_status: bool,
+    pub special_tips: Option<String>,
+    /// confirmation number for balance unlock
+    pub un_lock_confirm: u32,
+    /// shown only when "withdrawEnable" is false.
+    pub withdraw_desc: Option<String>,
+    pub withdraw_enable: bool,
+    #[serde(with = "string_or_float")]
+    pub withdraw_fee: f64,
+    #[serde(with = "string_or_float")]
+    pub withdraw_min: f64,
+    // pub insert_time: Option<u64>, //commented out for now, because they are not inside the actual response (only the api doc example)
+    // pub update_time: Option<u64>,
+    pub withdraw_integer_multiple: Option<String>,
+}
+
+/// Response to a withdraw request
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawId {
+    pub id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetDetail {
+    #[serde(with = "string_or_float")]
+    pub min_withdraw_amount: f64,
+    /// false if ALL of networks' are false
+    pub deposit_status: bool,
+    #[serde(with = "string_or_float")]
+    pub withdraw_fee: f64,
+    /// false if ALL of networks' are false
+    pub withdraw_status: bool,
+    /// reason
+    pub deposit_tip: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DepositAddress {
+    pub address: String,
+    pub coin: String,
+    pub tag: String,
+    pub url: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UniversalTransferHistory {
+    pub total: u64,
+    #[serde(default)]
+    pub rows: Vec<UniversalTransferRecord>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UniversalTransferRecord {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    #[serde(rename = "type")]
+    pub transfer_type: String,
+    pub status: String,
+    pub tran_id: u64,
+    pub timestamp: u64,
+}
+
+/// Assets that can be converted to BNB, response to the dust-btc request
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DustAssets {
+    pub details: Vec<DustAsset>,
+    #[serde(with = "string_or_float")]
+    pub total_transfer_btc: f64,
+    #[serde(rename = "totalTransferBNB", with = "string_or_float")]
+    pub total_transfer_bnb: f64,
+    /// Commission fee
+    #[serde(with = "string_or_float")]
+    pub dribblet_percentage: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DustAsset {
+    pub asset: String,
+    pub asset_full_name: String,
+    #[serde(with = "string_or_float")]
+    pub amount_free: f64,
+    #[serde(rename = "toBTC", with = "string_or_float")]
+    pub to_btc: f64,
+    #[serde(rename = "toBNB", with = "string_or_float")]
+    pub to_bnb: f64,
+    #[serde(rename = "toBNBOffExchange", with = "string_or_float")]
+    pub to_bnb_off_exchange: f64,
+    #[serde(with = "string_or_float")]
+    pub exchange: f64,
+}
+
+/// Response to a dust conversion
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DustTransfer {
+    #[serde(with = "string_or_float")]
+    pub total_service_charge: f64,
+    /// Spelling as returned by the API
+    #[serde(with = "string_or_float")]
+    pub total_transfered: f64,
+    pub transfer_result: Vec<DustTransferResult>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DustTransferResult {
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub from_asset: String,
+    pub operate_time: u64,
+    #[serde(with = "string_or_float")]
+    pub service_charge_amount: f64,
+    pub tran_id: u64,
+    #[serde(with = "string_or_float")]
+    pub transfered_amount: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DustLog {
+    pub total: u64,
+    #[serde(default)]
+    pub user_asset_dribblets: Vec<DustLogEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DustLogEntry {
+    pub operate_time: u64,
+    #[serde(with = "string_or_float")]
+    pub total_transfered_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub total_service_charge_amount: f64,
+    pub trans_id: u64,
+    pub user_asset_dribblet_details: Vec<DustLogDetail>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DustLogDetail {
+    pub trans_id: u64,
+    #[serde(with = "string_or_float")]
+    pub service_charge_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub operate_time: u64,
+    #[serde(with = "string_or_float")]
+    pub transfered_amount: f64,
+    pub from_asset: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetDividends {
+    pub rows: Vec<AssetDividend>,
+    pub total: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetDividend {
+    pub id: u64,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub asset: String,
+    pub div_time: u64,
+    pub en_info: String,
+    pub tran_id: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeFee {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub maker_commission: f64,
+    #[serde(with = "string_or_float")]
+    pub taker_commission: f64,
+}
+
+/// Balance of an asset in the funding wallet or in the user assets
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetBalance {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub free: f64,
+    #[serde(with = "string_or_float")]
+    pub locked: f64,
+    #[serde(with = "string_or_float")]
+    pub freeze: f64,
+    #[serde(with = "string_or_float")]
+    pub withdrawing: f64,
+    /// Only returned for user assets
+    #[serde(default, with = "string_or_float_opt")]
+    pub ipoable: Option<f64>,
+    /// "0" unless the BTC valuation was requested
+    #[serde(with = "string_or_float")]
+    pub btc_valuation: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountList {
+    pub sub_accounts: Vec<SubAccountInfo>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountInfo {
+    pub email: String,
+    pub is_freeze: bool,
+    pub create_time: u64,
+    #[serde(default)]
+    pub is_managed_sub_account: bool,
+    #[serde(default)]
+    pub is_asset_management_sub_account: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountEmail {
+    pub email: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountAssets {
+    pub balances: Vec<SubAccountBalance>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountBalance {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub free: f64,
+    #[serde(with = "string_or_float")]
+    pub locked: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountSpotSummary {
+    pub total_count: u64,
+    #[serde(with = "string_or_float")]
+    pub master_account_total_asset: f64,
+    pub spot_sub_user_asset_btc_vo_list: Vec<SubAccountSpotAsset>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountSpotAsset {
+    pub email: String,
+    /// Value in BTC
+    #[serde(with = "string_or_float")]
+    pub total_asset: f64,
+}
+
+/// Futures summary of the sub-accounts, USD-M or COIN-M depending on the request.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountFuturesSummary {
+    #[serde(default)]
+    pub future_account_summary_resp: Option<UsdMFuturesSummary>,
+    #[serde(default)]
+    pub delivery_account_summary_resp: Option<CoinMFuturesSummary>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UsdMFuturesSummary {
+    #[serde(with = "string_or_float")]
+    pub total_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_maintenance_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_margin_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub total_open_order_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_position_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_unrealized_profit: f64,
+    #[serde(with = "string_or_float")]
+    pub total_wallet_balance: f64,
+    pub asset: String,
+    pub sub_account_list: Vec<UsdMFuturesSubAccount>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UsdMFuturesSubAccount {
+    pub email: String,
+    #[serde(with = "string_or_float")]
+    pub total_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_maintenance_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_margin_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub total_open_order_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_position_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_unrealized_profit: f64,
+    #[serde(with = "string_or_float")]
+    pub total_wallet_balance: f64,
+    pub asset: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinMFuturesSummary {
+    #[serde(rename = "totalMarginBalanceOfBTC", with = "string_or_float")]
+    pub total_margin_balance_of_btc: f64,
+    #[serde(rename = "totalUnrealizedProfitOfBTC", with = "string_or_float")]
+    pub total_unrealized_profit_of_btc: f64,
+    #[serde(rename = "totalWalletBalanceOfBTC", with = "string_or_float")]
+    pub total_wallet_balance_of_btc: f64,
+    pub asset: String,
+    pub sub_account_list: Vec<CoinMFuturesSubAccount>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinMFuturesSubAccount {
+    pub email: String,
+    #[serde(with = "string_or_float")]
+    pub total_margin_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub total_unrealized_profit: f64,
+    #[serde(with = "string_or_float")]
+    pub total_wallet_balance: f64,
+    pub asset: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountTransferId {
+    pub tran_id: u64,
+    #[serde(default)]
+    pub client_tran_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountTransferHistory {
+    pub result: Vec<SubAccountTransfer>,
+    pub total_count: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountTransfer {
+    pub tran_id: u64,
+    pub from_email: String,
+    pub to_email: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub create_time_stamp: u64,
+    pub from_account_type: String,
+    pub to_account_type: String,
+    pub status: String,
+    #[serde(default)]
+    pub client_tran_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountFuturesStatus {
+    pub email: String,
+    pub is_futures_enabled: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountMarginStatus {
+    pub email: String,
+    pub is_margin_enabled: bool,
+}
+
+/// IP restriction of a sub-account API key
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountIpRestriction {
+    /// "true" or "false", returned when querying or deleting IPs
+    #[serde(default)]
+    pub ip_restrict: Option<String>,
+    /// "1" unrestricted or "2" restricted, returned when updating the restriction
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub ip_list: Vec<String>,
+    pub update_time: u64,
+    pub api_key: String,
+}
+
+/// Page of a Simple Earn list or history request
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EarnPage<T> {
+    #[serde(default = "Vec::new")]
+    pub rows: Vec<T>,
+    pub total: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleProduct {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub latest_annual_percentage_rate: f64,
+    /// Annual percentage rate per tier, e.g. "0-5BTC"
+    #[serde(default)]
+    pub tier_annual_percentage_rate: Option<BTreeMap<String, f64>>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub air_drop_percentage_rate: Option<f64>,
+    pub can_purchase: bool,
+    pub can_redeem: bool,
+    pub is_sold_out: bool,
+    pub hot: bool,
+    #[serde(with = "string_or_float")]
+    pub min_purchase_amount: f64,
+    pub product_id: String,
+    pub subscription_start_time: u64,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedProduct {
+    pub project_id: String,
+    pub detail: LockedProductDetail,
+    pub quota: LockedProductQuota,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedProductDetail {
+    pub asset: String,
+    pub reward_asset: String,
+    /// Lock period in days
+    pub duration: u32,
+    pub renewable: bool,
+    pub is_sold_out: bool,
+    #[serde(with = "string_or_float")]
+    pub apr: f64,
+    pub status: String,
+    #[serde(with = "string_or_u64")]
+    pub subscription_start_time: u64,
+    #[serde(default)]
+    pub extra_reward_asset: Option<String>,
+    #[serde(rename = "extraRewardAPR", default, with = "string_or_float_opt")]
+    pub extra_reward_apr: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedProductQuota {
+    #[serde(with = "string_or_float")]
+    pub total_personal_quota: f64,
+    #[serde(with = "string_or_float")]
+    pub minimum: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EarnSubscription {
+    pub purchase_id: u64,
+    /// Only returned for locked products
+    #[serde(default)]
+    pub position_id: Option<String>,
+    pub success: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EarnRedemption {
+    pub redeem_id: u64,
+    pub success: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexiblePosition {
+    #[serde(with = "string_or_float")]
+    pub total_amount: f64,
+    #[serde(default)]
+    pub tier_annual_percentage_rate: Option<BTreeMap<String, f64>>,
+    #[serde(with = "string_or_float")]
+    pub latest_annual_percentage_rate: f64,
+    #[serde(default, with = "string_or_float_opt")]
+    pub yesterday_airdrop_percentage_rate: Option<f64>,
+    pub asset: String,
+    #[serde(default)]
+    pub air_drop_asset: Option<String>,
+    pub can_redeem: bool,
+    #[serde(with = "string_or_float")]
+    pub collateral_amount: f64,
+    pub product_id: String,
+    #[serde(with = "string_or_float")]
+    pub yesterday_real_time_rewards: f64,
+    #[serde(with = "string_or_float")]
+    pub cumulative_bonus_rewards: f64,
+    #[serde(with = "string_or_float")]
+    pub cumulative_real_time_rewards: f64,
+    #[serde(with = "string_or_float")]
+    pub cumulative_total_rewards: f64,
+    pub auto_subscribe: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedPosition {
+    #[serde(with = "string_or_u64")]
+    pub position_id: u64,
+    pub project_id: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    #[serde(with = "string_or_u64")]
+    pub purchase_time: u64,
+    /// Lock period in days
+    #[serde(with = "string_or_u64")]
+    pub duration: u64,
+    #[serde(with = "string_or_u64")]
+    pub accrual_days: u64,
+    pub reward_asset: String,
+    #[serde(rename = "APY", with = "string_or_float")]
+    pub apy: f64,
+    #[serde(with = "string_or_float")]
+    pub reward_amt: f64,
+    #[serde(default)]
+    pub extra_reward_asset: Option<String>,
+    #[serde(rename = "extraRewardAPR", default, with = "string_or_float_opt")]
+    pub extra_reward_apr: Option<f64>,
+    #[serde(with = "string_or_float")]
+    pub next_pay: f64,
+    #[serde(with = "string_or_u64")]
+    pub next_pay_date: u64,
+    #[serde(with = "string_or_u64")]
+    pub rewards_end_date: u64,
+    #[serde(with = "string_or_u64")]
+    pub deliver_date: u64,
+    #[serde(default, with = "string_or_float_opt")]
+    pub redeem_amount_early: Option<f64>,
+    #[serde(with = "string_or_float")]
+    pub redeeming_amt: f64,
+    #[serde(default)]
+    pub redeem_to: Option<String>,
+    pub can_redeem_early: bool,
+    pub auto_subscribe: bool,
+    #[serde(rename = "type")]
+    pub position_type: String,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleSubscriptionRecord {
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub asset: String,
+    pub time: u64,
+    pub purchase_id: u64,
+    #[serde(default)]
+    pub product_id: Option<String>,
+    #[serde(rename = "type")]
+    pub record_type: String,
+    #[serde(default)]
+    pub source_account: Option<String>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub amt_from_spot: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub amt_from_funding: Option<f64>,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedSubscriptionRecord {
+    #[serde(with = "string_or_u64")]
+    pub position_id: u64,
+    pub purchase_id: u64,
+    #[serde(default)]
+    pub project_id: Option<String>,
+    pub time: u64,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    #[serde(with = "string_or_u64")]
+    pub lock_period: u64,
+    #[serde(rename = "type")]
+    pub record_type: String,
+    #[serde(default)]
+    pub source_account: Option<String>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub amt_from_spot: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub amt_from_funding: Option<f64>,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleRedemptionRecord {
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub asset: String,
+    pub time: u64,
+    pub product_id: String,
+    pub redeem_id: u64,
+    #[serde(default)]
+    pub dest_account: Option<String>,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedRedemptionRecord {
+    #[serde(with = "string_or_u64")]
+    pub position_id: u64,
+    pub redeem_id: u64,
+    pub time: u64,
+    pub asset: String,
+    #[serde(with = "string_or_u64")]
+    pub lock_period: u64,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    #[serde(rename = "type")]
+    pub record_type: String,
+    #[serde(with = "string_or_u64")]
+    pub deliver_date: u64,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleRewardRecord {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub rewards: f64,
+    /// Id of the flexible product
+    pub project_id: String,
+    #[serde(rename = "type")]
+    pub reward_type: String,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedRewardRecord {
+    #[serde(with = "string_or_u64")]
+    pub position_id: u64,
+    pub time: u64,
+    pub asset: String,
+    #[serde(with = "string_or_u64")]
+    pub lock_period: u64,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    #[serde(rename = "type")]
+    pub reward_type: String,
+}
+
+/// Flexible product amount used as collateral
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CollateralRecord {
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub product_id: String,
+    pub asset: String,
+    pub create_time: u64,
+    #[serde(rename = "type")]
+    pub record_type: String,
+    pub product_name: String,
+    pub order_id: u64,
+}
+
+/// Pair that can be converted, part of the Convert exchange info
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertPair {
+    pub from_asset: String,
+    pub to_asset: String,
+    #[serde(with = "string_or_float")]
+    pub from_asset_min_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub from_asset_max_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub to_asset_min_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub to_asset_max_amount: f64,
+}
+
+/// Convert quote, it can be accepted until valid_timestamp
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertQuote {
+    pub quote_id: String,
+    /// Amount of the to asset received for one from asset
+    #[serde(with = "string_or_float")]
+    pub ratio: f64,
+    #[serde(with = "string_or_float")]
+    pub inverse_ratio: f64,
+    pub valid_timestamp: u64,
+    #[serde(with = "string_or_float")]
+    pub to_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub from_amount: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ConvertOrderStatus {
+    Process,
+    AcceptSuccess,
+    Success,
+    Fail,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Response to accepting a quote
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertAcceptance {
+    #[serde(with = "string_or_u64")]
+    pub order_id: u64,
+    pub create_time: u64,
+    pub order_status: ConvertOrderStatus,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertOrder {
+    #[serde(default)]
+    pub quote_id: Option<String>,
+    #[serde(with = "string_or_u64")]
+    pub order_id: u64,
+    pub order_status: ConvertOrderStatus,
+    pub from_asset: String,
+    #[serde(with = "string_or_float")]
+    pub from_amount: f64,
+    pub to_asset: String,
+    #[serde(with = "string_or_float")]
+    pub to_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub ratio: f64,
+    #[serde(with = "string_or_float")]
+    pub inverse_ratio: f64,
+    pub create_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertTradeFlow {
+    pub list: Vec<ConvertOrder>,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub limit: u16,
+    pub more_data: bool,
+}
+
+/// Permissions of the API key
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiRestrictions {
+    pub ip_restrict: bool,
+    pub create_time: u64,
+    #[serde(default)]
+    pub enable_reading: bool,
+    #[serde(default)]
+    pub enable_spot_and_margin_trading: bool,
+    #[serde(default)]
+    pub enable_withdrawals: bool,
+    #[serde(default)]
+    pub enable_internal_transfer: bool,
+    #[serde(default)]
+    pub enable_margin: bool,
+    #[serde(default)]
+    pub enable_futures: bool,
+    #[serde(default)]
+    pub permits_universal_transfer: bool,
+    #[serde(default)]
+    pub enable_vanilla_options: bool,
+    #[serde(default)]
+    pub enable_portfolio_margin_trading: bool,
+    /// Expiration time of the spot and margin trading permission, if any
+    #[serde(default)]
+    pub trading_authority_expiration_time: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountStatus {
+    /// "Normal" unless the account is restricted
+    pub data: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiTradingStatus {
+    pub data: ApiTradingStatusData,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiTradingStatusData {
+    pub is_locked: bool,
+    /// Time the trading ban is lifted, 0 if not locked
+    pub planned_recover_time: u64,
+    pub trigger_condition: BTreeMap<String, f64>,
+    /// Indicators by symbol, or "ALL" for account level indicators
+    #[serde(default)]
+    pub indicators: BTreeMap<String, Vec<TradingIndicator>>,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TradingIndicator {
+    #[serde(rename = "i")]
+    pub indicator: String,
+    #[serde(rename = "c")]
+    pub count: u64,
+    #[serde(rename = "v")]
+    pub current_value: f64,
+    #[serde(rename = "t")]
+    pub trigger_value: f64,
+}
+
+/// Status of a deposit, as returned by the deposit history request
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(from = "u8", into = "u8")]
+pub enum DepositStatus {
+    Pending,
+    Success,
+    Rejected,
+    /// Credited but cannot be withdrawn yet
+    CreditedButCannotWithdraw,
+    WrongDeposit,
+    WaitingUserConfirm,
+    Other(u8),
+}
+
+impl From<u8> for DepositStatus {
+    fn from(status: u8) -> Self {
+        match status {
+            0 => DepositStatus::Pending,
+            1 => DepositStatus::Success,
+            2 => DepositStatus::Rejected,
+            6 => DepositStatus::CreditedButCannotWithdraw,
+            7 => DepositStatus::WrongDeposit,
+            8 => DepositStatus::WaitingUserConfirm,
+            other => DepositStatus::Other(other),
+        }
+    }
+}
+
+impl From<DepositStatus> for u8 {
+    fn from(status: DepositStatus) -> Self {
+        match status {
+            DepositStatus::Pending => 0,
+            DepositStatus::Success => 1,
+            DepositStatus::Rejected => 2,
+            DepositStatus::CreditedButCannotWithdraw => 6,
+            DepositStatus::WrongDeposit => 7,
+            DepositStatus::WaitingUserConfirm => 8,
+            DepositStatus::Other(other) => other,
+        }
+    }
+}
+
+/// Status of a withdrawal, as returned by the withdraw history request
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(from = "u8", into = "u8")]
+pub enum WithdrawStatus {
+    EmailSent,
+    Cancelled,
+    AwaitingApproval,
+    Rejected,
+    Processing,
+    Failure,
+    Completed,
+    Other(u8),
+}
+
+impl From<u8> for WithdrawStatus {
+    fn from(status: u8) -> Self {
+        match status {
+            0 => WithdrawStatus::EmailSent,
+            1 => WithdrawStatus::Cancelled,
+            2 => WithdrawStatus::AwaitingApproval,
+            3 => WithdrawStatus::Rejected,
+            4 => WithdrawStatus::Processing,
+            5 => WithdrawStatus::Failure,
+            6 => WithdrawStatus::Completed,
+            other => WithdrawStatus::Other(other),
+        }
+    }
+}
+
+impl From<WithdrawStatus> for u8 {
+    fn from(status: WithdrawStatus) -> Self {
+        match status {
+            WithdrawStatus::EmailSent => 0,
+            WithdrawStatus::Cancelled => 1,
+            WithdrawStatus::AwaitingApproval => 2,
+            WithdrawStatus::Rejected => 3,
+            WithdrawStatus::Processing => 4,
+            WithdrawStatus::Failure => 5,
+            WithdrawStatus::Completed => 6,
+            WithdrawStatus::Other(other) => other,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositRecord {
+    pub id: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub coin: String,
+    pub network: String,
+    pub status: DepositStatus,
+    pub address: String,
+    pub address_tag: String,
+    pub tx_id: String,
+    pub insert_time: u64,
+    #[serde(default)]
+    pub complete_time: Option<u64>,
+    /// 1 for internal transfers, 0 for external ones
+    pub transfer_type: u8,
+    /// Confirmations, e.g. "12/12"
+    pub confirm_times: String,
+    pub unlock_confirm: u32,
+    /// 0 for the spot wallet, 1 for the funding wallet
+    pub wallet_type: u8,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawRecord {
+    pub id: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    #[serde(with = "string_or_float")]
+    pub transaction_fee: f64,
+    pub coin: String,
+    pub status: WithdrawStatus,
+    pub address: String,
+    #[serde(default)]
+    pub address_tag: Option<String>,
+    #[serde(default)]
+    pub tx_id: Option<String>,
+    /// UTC time, e.g. "2019-10-12 11:12:02"
+    pub apply_time: String,
+    pub network: String,
+    /// 1 for internal transfers, 0 for external ones
+    pub transfer_type: u8,
+    #[serde(default)]
+    pub withdraw_order_id: Option<String>,
+    /// Reason for a failure
+    #[serde(default)]
+    pub info: Option<String>,
+    /// Confirmations of the withdrawal
+    #[serde(default)]
+    pub confirm_no: Option<u32>,
+    /// 0 for the spot wallet, 1 for the funding wallet
+    #[serde(default)]
+    pub wallet_type: Option<u8>,
+    #[serde(default)]
+    pub tx_key: Option<String>,
+    /// UTC time, e.g. "2019-10-12 11:15:02"
+    #[serde(default)]
+    pub complete_time: Option<String>,
+}
+
+pub(crate) mod string_or_float {
+    use std::fmt;
+
+    use serde::{de, Serializer, Deserialize, Deserializer};
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: fmt::Display,
+        S: Serializer,
+    {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<f64, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum StringOrFloat {
+            String(String),
+            Float(f64),
         }
 
         match StringOrFloat::deserialize(deserializer)? {
@@ -1248,7 +2706,7 @@ pub(crate) mod string_or_float {
 pub(crate) mod string_or_float_opt {
     use std::fmt;
 
-    use serde::{Serializer, Deserialize, Deserializer};
+    use serde::{Serializer, Deserializer};
 
     pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -1265,13 +2723,6 @@ pub(crate) mod string_or_float_opt {
     where
         D: Deserializer<'de>,
     {
-        #[derive(Deserialize)]
-        #[serde(untagged)]
-        enum StringOrFloat {
-            String(String),
-            Float(f64),
-        }
-
         Ok(Some(crate::model::string_or_float::deserialize(
             deserializer,
         )?))
@@ -1308,3 +2759,34 @@ pub(crate) mod string_or_bool {
         }
     }
 }
+
+pub(crate) mod string_or_u64 {
+    use std::fmt;
+
+    use serde::{de, Serializer, Deserialize, Deserializer};
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: fmt::Display,
+        S: Serializer,
+    {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<u64, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum StringOrU64 {
+            String(String),
+            U64(u64),
+        }
+
+        match StringOrU64::deserialize(deserializer)? {
+            StringOrU64::String(s) => s.parse().map_err(de::Error::custom),
+            StringOrU64::U64(i) => Ok(i),
+        }
+    }
+}
@@ -0,0 +1,259 @@
+use std::collections::BTreeMap;
+
+use crate::util::*;
+use crate::errors::*;
+use crate::preflight::{ApiPermission, RequiredPermissions};
+use crate::client::Client;
+use crate::api::{API, Eapi};
+use crate::account::{OrderSide, TimeInForce};
+use crate::model::BatchResult;
+use super::model::{
+    CountdownCancelAll, CountdownCancelAllHeartbeat, MarginAccount, Order, Position, UserTrade,
+};
+
+/// Largest number of orders of a batch.
+pub const OPTIONS_BATCH_ORDERS_LIMIT: usize = 10;
+
+#[derive(Clone)]
+pub struct OptionsAccount {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl RequiredPermissions for OptionsAccount {
+    fn required_permissions() -> Vec<ApiPermission> {
+        vec![ApiPermission::Reading, ApiPermission::VanillaOptions]
+    }
+}
+
+pub enum OptionsOrderType {
+    Limit,
+    Market,
+}
+
+impl From<OptionsOrderType> for String {
+    fn from(item: OptionsOrderType) -> Self {
+        match item {
+            OptionsOrderType::Limit => String::from("LIMIT"),
+            OptionsOrderType::Market => String::from("MARKET"),
+        }
+    }
+}
+
+/// Order on an option, the quantity is a number of contracts.
+pub struct OptionsOrderRequest {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub order_type: OptionsOrderType,
+    pub quantity: f64,
+    pub price: Option<f64>,
+    pub time_in_force: Option<TimeInForce>,
+    pub reduce_only: Option<bool>,
+    pub post_only: Option<bool>,
+    pub client_order_id: Option<String>,
+    pub is_mmp: Option<bool>,
+}
+
+impl OptionsOrderRequest {
+    pub fn limit<S>(
+        symbol: S, side: OrderSide, quantity: f64, price: f64, time_in_force: TimeInForce,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            order_type: OptionsOrderType::Limit,
+            price: Some(price),
+            time_in_force: Some(time_in_force),
+            ..Self::market(symbol, side, quantity)
+        }
+    }
+
+    pub fn market<S>(symbol: S, side: OrderSide, quantity: f64) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            symbol: symbol.into(),
+            side,
+            order_type: OptionsOrderType::Market,
+            quantity,
+            price: None,
+            time_in_force: None,
+            reduce_only: None,
+            post_only: None,
+            client_order_id: None,
+            is_mmp: None,
+        }
+    }
+}
+
+impl OptionsAccount {
+    pub fn new_order(&self, order: OptionsOrderRequest) -> Result<Order> {
+        let order = Self::build_order(order);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client.post_signed(API::Options(Eapi::Order), request)
+    }
+
+    /// Places up to `OPTIONS_BATCH_ORDERS_LIMIT` orders, each one succeeds or fails on its own.
+    pub fn new_batch_orders(
+        &self, orders: Vec<OptionsOrderRequest>,
+    ) -> Result<Vec<std::result::Result<Order, BinanceContentError>>> {
+        if orders.is_empty() || orders.len() > OPTIONS_BATCH_ORDERS_LIMIT {
+            bail!(format!(
+                "A batch must have between 1 and {} orders, {} given",
+                OPTIONS_BATCH_ORDERS_LIMIT,
+                orders.len()
+            ));
+        }
+        let orders: Vec<BTreeMap<String, String>> =
+            orders.into_iter().map(Self::build_order).collect();
+
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("orders".into(), build_json_list_param(&orders)?);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        let results: Vec<BatchResult<Order>> = self
+            .client
+            .post_signed(API::Options(Eapi::BatchOrders), request)?;
+        Ok(results.into_iter().map(BatchResult::into_result).collect())
+    }
+
+    pub fn cancel_order<S>(&self, symbol: S, order_id: u64) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Options(Eapi::Order), Some(request))
+    }
+
+    pub fn cancel_order_with_client_id<S1, S2>(
+        &self, symbol: S1, client_order_id: S2,
+    ) -> Result<Order>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("clientOrderId".into(), client_order_id.into());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Options(Eapi::Order), Some(request))
+    }
+
+    // Positions of a symbol, or of all symbols
+    pub fn get_positions(&self, symbol: Option<String>) -> Result<Vec<Position>> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(symbol) = symbol {
+            parameters.insert("symbol".into(), symbol);
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Options(Eapi::Position), Some(request))
+    }
+
+    pub fn get_user_trades<S1, S2, S3, S4>(
+        &self, symbol: Option<String>, from_id: S1, start_time: S2, end_time: S3, limit: S4,
+    ) -> Result<Vec<UserTrade>>
+    where
+        S1: Into<Option<u64>>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(symbol) = symbol {
+            parameters.insert("symbol".into(), symbol);
+        }
+        if let Some(fi) = from_id.into() {
+            parameters.insert("fromId".into(), fi.to_string());
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), st.to_string());
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), et.to_string());
+        }
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), lt.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Options(Eapi::UserTrades), Some(request))
+    }
+
+    // Margin balances and Greeks of the options account
+    pub fn get_margin_account(&self) -> Result<MarginAccount> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Options(Eapi::MarginAccount), Some(request))
+    }
+
+    /// Cancels all open orders of the underlying once countdown_time milliseconds have passed
+    /// without a heartbeat, 0 disables the countdown.
+    pub fn countdown_cancel_all<S>(
+        &self, underlying: S, countdown_time: u64,
+    ) -> Result<CountdownCancelAll>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("underlying".into(), underlying.into());
+        parameters.insert("countdownTime".into(), countdown_time.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Options(Eapi::CountdownCancelAll), request)
+    }
+
+    /// Restarts the countdown of the underlyings, see `countdown_cancel_all`.
+    pub fn countdown_cancel_all_heartbeat<S: AsRef<str>>(
+        &self, underlyings: &[S],
+    ) -> Result<CountdownCancelAllHeartbeat> {
+        let underlyings: Vec<&str> = underlyings.iter().map(|u| u.as_ref()).collect();
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("underlyings".into(), underlyings.join(","));
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Options(Eapi::CountdownCancelAllHeartBeat), request)
+    }
+
+    fn build_order(order: OptionsOrderRequest) -> BTreeMap<String, String> {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), order.symbol);
+        parameters.insert("side".into(), order.side.into());
+        parameters.insert("type".into(), order.order_type.into());
+        parameters.insert("quantity".into(), order.quantity.to_string());
+
+        if let Some(price) = order.price {
+            parameters.insert("price".into(), price.to_string());
+        }
+        if let Some(time_in_force) = order.time_in_force {
+            parameters.insert("timeInForce".into(), time_in_force.into());
+        }
+        if let Some(reduce_only) = order.reduce_only {
+            parameters.insert("reduceOnly".into(), reduce_only.to_string());
+        }
+        if let Some(post_only) = order.post_only {
+            parameters.insert("postOnly".into(), post_only.to_string());
+        }
+        if let Some(client_order_id) = order.client_order_id {
+            parameters.insert("clientOrderId".into(), client_order_id);
+        }
+        if let Some(is_mmp) = order.is_mmp {
+            parameters.insert("isMmp".into(), is_mmp.to_string());
+        }
+
+        parameters
+    }
+}
@@ -0,0 +1,148 @@
+use crate::util::*;
+use crate::options::model::*;
+use crate::client::*;
+use crate::errors::*;
+use std::collections::BTreeMap;
+use crate::api::API;
+use crate::api::Eapi;
+
+#[derive(Clone)]
+pub struct OptionsMarket {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl OptionsMarket {
+    // Option contracts, assets and symbols
+    pub fn exchange_info(&self) -> Result<ExchangeInformation> {
+        self.client.get(API::Options(Eapi::ExchangeInfo), None)
+    }
+
+    // Spot index price of an underlying, e.g. BTCUSDT
+    pub fn get_index_price<S>(&self, underlying: S) -> Result<IndexPrice>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("underlying".into(), underlying.into());
+
+        let request = build_request(parameters);
+        self.client.get(API::Options(Eapi::Index), Some(request))
+    }
+
+    // Mark price and Greeks of a symbol, or of all symbols
+    pub fn get_mark_prices(&self, symbol: Option<String>) -> Result<Vec<MarkPrice>> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(symbol) = symbol {
+            parameters.insert("symbol".into(), symbol);
+        }
+
+        let request = build_request(parameters);
+        self.client.get(API::Options(Eapi::Mark), Some(request))
+    }
+
+    // Order book (Default 100; max 1000)
+    pub fn get_depth<S1, S2>(&self, symbol: S1, limit: S2) -> Result<OrderBook>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), lt.to_string());
+        }
+
+        let request = build_request(parameters);
+        self.client.get(API::Options(Eapi::Depth), Some(request))
+    }
+
+    // Returns up to 'limit' klines for given symbol and interval ("1m", "5m", ...)
+    pub fn get_klines<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, interval: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<Vec<Kline>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("interval".into(), interval.into());
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), lt.to_string());
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), st.to_string());
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), et.to_string());
+        }
+
+        let request = build_request(parameters);
+        self.client.get(API::Options(Eapi::Klines), Some(request))
+    }
+
+    // Recent trades (Default 100; max 500)
+    pub fn get_trades<S1, S2>(&self, symbol: S1, limit: S2) -> Result<Vec<Trade>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), lt.to_string());
+        }
+
+        let request = build_request(parameters);
+        self.client.get(API::Options(Eapi::Trades), Some(request))
+    }
+
+    // Open interest of all symbols of an underlying asset (e.g. BTC) expiring at expiration (e.g. 221225)
+    pub fn get_open_interest<S1, S2>(
+        &self, underlying_asset: S1, expiration: S2,
+    ) -> Result<Vec<OpenInterest>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("underlyingAsset".into(), underlying_asset.into());
+        parameters.insert("expiration".into(), expiration.into());
+
+        let request = build_request(parameters);
+        self.client
+            .get(API::Options(Eapi::OpenInterest), Some(request))
+    }
+
+    // Exercise results of expired options, of all underlyings if none is given
+    pub fn get_exercise_history<S1, S2, S3>(
+        &self, underlying: Option<String>, start_time: S1, end_time: S2, limit: S3,
+    ) -> Result<Vec<ExerciseRecord>>
+    where
+        S1: Into<Option<u64>>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(underlying) = underlying {
+            parameters.insert("underlying".into(), underlying);
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), st.to_string());
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), et.to_string());
+        }
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), lt.to_string());
+        }
+
+        let request = build_request(parameters);
+        self.client
+            .get(API::Options(Eapi::ExerciseHistory), Some(request))
+    }
+}
@@ -0,0 +1,4 @@
+pub mod account;
+pub mod market;
+pub mod model;
+pub mod userstream;
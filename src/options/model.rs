@@ -0,0 +1,330 @@
+use serde::{Deserialize, Serialize};
+use crate::model::{string_or_float, string_or_u64};
+
+pub use crate::model::{Asks, Bids, Filters, RateLimit};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeInformation {
+    pub timezone: String,
+    pub server_time: u64,
+    pub option_contracts: Vec<OptionContract>,
+    pub option_assets: Vec<OptionAsset>,
+    pub option_symbols: Vec<OptionSymbol>,
+    pub rate_limits: Vec<RateLimit>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionContract {
+    pub base_asset: String,
+    pub quote_asset: String,
+    pub underlying: String,
+    pub settle_asset: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OptionAsset {
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionSymbol {
+    pub symbol: String,
+    pub underlying: String,
+    /// CALL or PUT
+    pub side: String,
+    #[serde(with = "string_or_float")]
+    pub strike_price: f64,
+    pub expiry_date: u64,
+    /// Number of underlying per contract
+    pub unit: u64,
+    #[serde(with = "string_or_float")]
+    pub maker_fee_rate: f64,
+    #[serde(with = "string_or_float")]
+    pub taker_fee_rate: f64,
+    #[serde(with = "string_or_float")]
+    pub min_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub max_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub maintenance_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub min_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub min_maintenance_margin: f64,
+    pub price_scale: u16,
+    pub quantity_scale: u16,
+    pub quote_asset: String,
+    pub filters: Vec<Filters>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexPrice {
+    pub time: u64,
+    #[serde(with = "string_or_float")]
+    pub index_price: f64,
+}
+
+/// Mark price, implied volatilities and Greeks of an option
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkPrice {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub mark_price: f64,
+    #[serde(rename = "bidIV", with = "string_or_float")]
+    pub bid_iv: f64,
+    #[serde(rename = "askIV", with = "string_or_float")]
+    pub ask_iv: f64,
+    #[serde(rename = "markIV", with = "string_or_float")]
+    pub mark_iv: f64,
+    #[serde(with = "string_or_float")]
+    pub delta: f64,
+    #[serde(with = "string_or_float")]
+    pub theta: f64,
+    #[serde(with = "string_or_float")]
+    pub gamma: f64,
+    #[serde(with = "string_or_float")]
+    pub vega: f64,
+    #[serde(with = "string_or_float")]
+    pub high_price_limit: f64,
+    #[serde(with = "string_or_float")]
+    pub low_price_limit: f64,
+    #[serde(with = "string_or_float")]
+    pub risk_free_interest: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OrderBook {
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    #[serde(rename = "u")]
+    pub update_id: u64,
+    pub bids: Vec<Bids>,
+    pub asks: Vec<Asks>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Kline {
+    pub open_time: u64,
+    #[serde(with = "string_or_float")]
+    pub open: f64,
+    #[serde(with = "string_or_float")]
+    pub high: f64,
+    #[serde(with = "string_or_float")]
+    pub low: f64,
+    #[serde(with = "string_or_float")]
+    pub close: f64,
+    pub interval: String,
+    pub trade_count: u64,
+    /// Volume in contracts
+    #[serde(with = "string_or_float")]
+    pub volume: f64,
+    /// Volume in quote asset
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    #[serde(with = "string_or_float")]
+    pub taker_volume: f64,
+    #[serde(with = "string_or_float")]
+    pub taker_amount: f64,
+    pub close_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade {
+    pub id: u64,
+    pub trade_id: u64,
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub qty: f64,
+    #[serde(with = "string_or_float")]
+    pub quote_qty: f64,
+    /// 1 if the taker bought, -1 if the taker sold
+    pub side: i8,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterest {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub sum_open_interest: f64,
+    #[serde(with = "string_or_float")]
+    pub sum_open_interest_usd: f64,
+    #[serde(with = "string_or_u64")]
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExerciseRecord {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub strike_price: f64,
+    /// Settlement price of the underlying
+    #[serde(with = "string_or_float")]
+    pub real_strike_price: f64,
+    pub expiry_date: u64,
+    /// REALISTIC_VALUE_STRICKEN or EXTRINSIC_VALUE_EXPIRED
+    pub strike_result: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Order {
+    pub order_id: u64,
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub quantity: f64,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub fee: f64,
+    pub side: String,
+    #[serde(rename = "type")]
+    pub order_type: String,
+    pub time_in_force: String,
+    pub reduce_only: bool,
+    pub post_only: bool,
+    pub create_time: u64,
+    pub update_time: u64,
+    pub status: String,
+    #[serde(with = "string_or_float")]
+    pub avg_price: f64,
+    #[serde(default)]
+    pub client_order_id: Option<String>,
+    pub price_scale: u16,
+    pub quantity_scale: u16,
+    pub option_side: String,
+    pub quote_asset: String,
+    #[serde(default)]
+    pub mmp: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Position {
+    pub symbol: String,
+    /// LONG or SHORT
+    pub side: String,
+    #[serde(with = "string_or_float")]
+    pub entry_price: f64,
+    #[serde(with = "string_or_float")]
+    pub quantity: f64,
+    #[serde(with = "string_or_float")]
+    pub reducible_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub mark_value: f64,
+    #[serde(with = "string_or_float")]
+    pub ror: f64,
+    #[serde(rename = "unrealizedPNL", with = "string_or_float")]
+    pub unrealized_pnl: f64,
+    #[serde(with = "string_or_float")]
+    pub mark_price: f64,
+    #[serde(with = "string_or_float")]
+    pub strike_price: f64,
+    #[serde(with = "string_or_float")]
+    pub position_cost: f64,
+    pub expiry_date: u64,
+    pub price_scale: u16,
+    pub quantity_scale: u16,
+    pub option_side: String,
+    pub quote_asset: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserTrade {
+    pub id: u64,
+    pub trade_id: u64,
+    pub order_id: u64,
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub quantity: f64,
+    #[serde(with = "string_or_float")]
+    pub fee: f64,
+    #[serde(with = "string_or_float")]
+    pub realized_profit: f64,
+    pub side: String,
+    #[serde(rename = "type")]
+    pub order_type: String,
+    #[serde(with = "string_or_float")]
+    pub volatility: f64,
+    /// TAKER or MAKER
+    pub liquidity: String,
+    pub quote_asset: String,
+    pub time: u64,
+    pub price_scale: u16,
+    pub quantity_scale: u16,
+    pub option_side: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginAccount {
+    #[serde(rename = "asset")]
+    pub assets: Vec<MarginAsset>,
+    #[serde(rename = "greek")]
+    pub greeks: Vec<Greeks>,
+    pub time: u64,
+    #[serde(default)]
+    pub risk_level: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginAsset {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub margin_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub equity: f64,
+    #[serde(with = "string_or_float")]
+    pub available: f64,
+    #[serde(with = "string_or_float")]
+    pub locked: f64,
+    #[serde(rename = "unrealizedPNL", with = "string_or_float")]
+    pub unrealized_pnl: f64,
+}
+
+/// Greeks of the account, by underlying
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Greeks {
+    pub underlying: String,
+    #[serde(with = "string_or_float")]
+    pub delta: f64,
+    #[serde(with = "string_or_float")]
+    pub gamma: f64,
+    #[serde(with = "string_or_float")]
+    pub theta: f64,
+    #[serde(with = "string_or_float")]
+    pub vega: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CountdownCancelAll {
+    pub underlying: String,
+    #[serde(with = "string_or_u64")]
+    pub countdown_time: u64,
+}
+
+/// Underlyings whose countdown was restarted
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CountdownCancelAllHeartbeat {
+    pub underlyings: Vec<String>,
+}
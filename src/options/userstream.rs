@@ -0,0 +1,35 @@
+use crate::model::*;
+use crate::client::*;
+use crate::errors::*;
+use crate::preflight::{ApiPermission, RequiredPermissions};
+use crate::api::API;
+use crate::api::Eapi;
+
+#[derive(Clone)]
+pub struct OptionsUserStream {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl RequiredPermissions for OptionsUserStream {
+    fn required_permissions() -> Vec<ApiPermission> {
+        vec![ApiPermission::Reading, ApiPermission::VanillaOptions]
+    }
+}
+
+impl OptionsUserStream {
+    // User Stream
+    pub fn start(&self) -> Result<UserDataStream> {
+        self.client.post(API::Options(Eapi::UserDataStream))
+    }
+
+    pub fn keep_alive(&self, listen_key: &str) -> Result<Success> {
+        self.client
+            .put(API::Options(Eapi::UserDataStream), listen_key)
+    }
+
+    pub fn close(&self, listen_key: &str) -> Result<Success> {
+        self.client
+            .delete(API::Options(Eapi::UserDataStream), listen_key)
+    }
+}
@@ -0,0 +1,139 @@
+use crate::errors::*;
+use crate::model::{AccountStatus, ApiRestrictions, ApiTradingStatus};
+use crate::savings::Savings;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Error codes of an invalid API key format, an invalid key, IP address or permissions,
+// and an invalid signature
+const KEY_REJECTED: [i16; 3] = [-2014, -2015, -1022];
+
+/// Permission of an API key, as listed by `Savings::get_api_restrictions`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ApiPermission {
+    Reading,
+    SpotAndMarginTrading,
+    Margin,
+    Futures,
+    VanillaOptions,
+    Withdrawals,
+    InternalTransfer,
+    UniversalTransfer,
+}
+
+impl ApiPermission {
+    pub fn is_granted(self, restrictions: &ApiRestrictions) -> bool {
+        match self {
+            ApiPermission::Reading => restrictions.enable_reading,
+            ApiPermission::SpotAndMarginTrading => restrictions.enable_spot_and_margin_trading,
+            ApiPermission::Margin => restrictions.enable_margin,
+            ApiPermission::Futures => restrictions.enable_futures,
+            ApiPermission::VanillaOptions => restrictions.enable_vanilla_options,
+            ApiPermission::Withdrawals => restrictions.enable_withdrawals,
+            ApiPermission::InternalTransfer => restrictions.enable_internal_transfer,
+            ApiPermission::UniversalTransfer => restrictions.permits_universal_transfer,
+        }
+    }
+}
+
+/// Permissions the API key needs for the signed requests of a client.
+///
+/// Withdrawals and transfers are not included, they are required with `Preflight::permission`.
+pub trait RequiredPermissions {
+    fn required_permissions() -> Vec<ApiPermission>;
+}
+
+/// Permissions checked by `Preflight::run`, usually one `client` per client type
+/// the application constructs.
+#[derive(Debug, Clone, Default)]
+pub struct Preflight {
+    pub permissions: Vec<ApiPermission>,
+}
+
+impl Preflight {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn client<C: RequiredPermissions>(self) -> Self {
+        C::required_permissions()
+            .into_iter()
+            .fold(self, Preflight::permission)
+    }
+
+    pub fn permission(mut self, permission: ApiPermission) -> Self {
+        if !self.permissions.contains(&permission) {
+            self.permissions.push(permission);
+        }
+        self
+    }
+
+    /// Checks the key of the wallet client before the application starts trading.
+    ///
+    /// Fails with every problem found: missing permissions, expired trading permission,
+    /// restricted account or locked API trading. A key that is rejected outright (wrong key
+    /// or IP address not whitelisted) fails on the first request.
+    pub fn run(&self, savings: &Savings) -> Result<ApiRestrictions> {
+        let restrictions = match savings.get_api_restrictions() {
+            Ok(restrictions) => restrictions,
+            Err(Error(ErrorKind::BinanceError(ref response), _))
+                if KEY_REJECTED.contains(&response.code) =>
+            {
+                bail!(format!(
+                    "Preflight failed, the API key was rejected (invalid key or IP address not whitelisted?): {}",
+                    response.msg
+                ))
+            }
+            Err(e) => return Err(e),
+        };
+        let status = savings.get_account_status()?;
+        let trading_status = savings.get_api_trading_status()?;
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64;
+        let problems = self.check(&restrictions, &status, &trading_status, now);
+        if !problems.is_empty() {
+            bail!(format!("Preflight failed: {}", problems.join("; ")));
+        }
+
+        Ok(restrictions)
+    }
+
+    /// Problems found with the key, empty if it can be used.
+    pub fn check(
+        &self, restrictions: &ApiRestrictions, status: &AccountStatus,
+        trading_status: &ApiTradingStatus, now: u64,
+    ) -> Vec<String> {
+        let mut problems = vec![];
+
+        let mut permissions = self.permissions.clone();
+        permissions.sort();
+        for permission in permissions {
+            if !permission.is_granted(restrictions) {
+                problems.push(format!("{:?} permission is not enabled", permission));
+            } else if permission == ApiPermission::Withdrawals && !restrictions.ip_restrict {
+                // Withdrawals are refused for keys without an IP access restriction
+                problems.push("Withdrawals permission requires an IP restricted key".into());
+            } else if permission == ApiPermission::SpotAndMarginTrading {
+                if let Some(expiration) = restrictions.trading_authority_expiration_time {
+                    if expiration > 0 && expiration <= now {
+                        problems.push(format!(
+                            "SpotAndMarginTrading permission expired at {}",
+                            expiration
+                        ));
+                    }
+                }
+            }
+        }
+
+        if status.data != "Normal" {
+            problems.push(format!("Account status is {}", status.data));
+        }
+        if trading_status.data.is_locked {
+            problems.push(format!(
+                "API trading is locked until {}",
+                trading_status.data.planned_recover_time
+            ));
+        }
+
+        problems
+    }
+}
@@ -5,6 +5,13 @@ use crate::errors::*;
 use std::collections::BTreeMap;
 use crate::api::API;
 use crate::api::Sapi;
+use crate::preflight::{ApiPermission, RequiredPermissions};
+use regex::Regex;
+
+/// Longest time range accepted by the deposit and withdraw history requests (90 days).
+pub const CAPITAL_HISTORY_MAX_WINDOW: u64 = 90 * 24 * 60 * 60 * 1000;
+/// Largest page returned by the deposit and withdraw history requests.
+pub const CAPITAL_HISTORY_PAGE_LIMIT: u16 = 1000;
 
 #[derive(Clone)]
 pub struct Savings {
@@ -12,6 +19,266 @@ pub struct Savings {
     pub recv_window: u64,
 }
 
+impl RequiredPermissions for Savings {
+    fn required_permissions() -> Vec<ApiPermission> {
+        vec![ApiPermission::Reading]
+    }
+}
+
+/// Wallet the withdrawal is taken from.
+pub enum WalletType {
+    Spot,
+    Funding,
+}
+
+impl From<WalletType> for String {
+    fn from(item: WalletType) -> Self {
+        match item {
+            WalletType::Spot => String::from("0"),
+            WalletType::Funding => String::from("1"),
+        }
+    }
+}
+
+/// Source and destination wallets of a universal transfer.
+///
+/// MAIN is the spot wallet, UMFUTURE the USD-M futures wallet and CMFUTURE the COIN-M futures wallet.
+pub enum TransferType {
+    MainUmFuture,
+    MainCmFuture,
+    MainMargin,
+    UmFutureMain,
+    UmFutureMargin,
+    CmFutureMain,
+    CmFutureMargin,
+    MarginMain,
+    MarginUmFuture,
+    MarginCmFuture,
+    IsolatedMarginMargin,
+    MarginIsolatedMargin,
+    IsolatedMarginIsolatedMargin,
+    MainFunding,
+    FundingMain,
+    FundingUmFuture,
+    UmFutureFunding,
+    MarginFunding,
+    FundingMargin,
+    FundingCmFuture,
+    CmFutureFunding,
+    MainOption,
+    OptionMain,
+    UmFutureOption,
+    OptionUmFuture,
+    MarginOption,
+    OptionMargin,
+    FundingOption,
+    OptionFunding,
+    MainPortfolioMargin,
+    PortfolioMarginMain,
+}
+
+impl From<TransferType> for String {
+    fn from(item: TransferType) -> Self {
+        match item {
+            TransferType::MainUmFuture => String::from("MAIN_UMFUTURE"),
+            TransferType::MainCmFuture => String::from("MAIN_CMFUTURE"),
+            TransferType::MainMargin => String::from("MAIN_MARGIN"),
+            TransferType::UmFutureMain => String::from("UMFUTURE_MAIN"),
+            TransferType::UmFutureMargin => String::from("UMFUTURE_MARGIN"),
+            TransferType::CmFutureMain => String::from("CMFUTURE_MAIN"),
+            TransferType::CmFutureMargin => String::from("CMFUTURE_MARGIN"),
+            TransferType::MarginMain => String::from("MARGIN_MAIN"),
+            TransferType::MarginUmFuture => String::from("MARGIN_UMFUTURE"),
+            TransferType::MarginCmFuture => String::from("MARGIN_CMFUTURE"),
+            TransferType::IsolatedMarginMargin => String::from("ISOLATEDMARGIN_MARGIN"),
+            TransferType::MarginIsolatedMargin => String::from("MARGIN_ISOLATEDMARGIN"),
+            TransferType::IsolatedMarginIsolatedMargin => {
+                String::from("ISOLATEDMARGIN_ISOLATEDMARGIN")
+            }
+            TransferType::MainFunding => String::from("MAIN_FUNDING"),
+            TransferType::FundingMain => String::from("FUNDING_MAIN"),
+            TransferType::FundingUmFuture => String::from("FUNDING_UMFUTURE"),
+            TransferType::UmFutureFunding => String::from("UMFUTURE_FUNDING"),
+            TransferType::MarginFunding => String::from("MARGIN_FUNDING"),
+            TransferType::FundingMargin => String::from("FUNDING_MARGIN"),
+            TransferType::FundingCmFuture => String::from("FUNDING_CMFUTURE"),
+            TransferType::CmFutureFunding => String::from("CMFUTURE_FUNDING"),
+            TransferType::MainOption => String::from("MAIN_OPTION"),
+            TransferType::OptionMain => String::from("OPTION_MAIN"),
+            TransferType::UmFutureOption => String::from("UMFUTURE_OPTION"),
+            TransferType::OptionUmFuture => String::from("OPTION_UMFUTURE"),
+            TransferType::MarginOption => String::from("MARGIN_OPTION"),
+            TransferType::OptionMargin => String::from("OPTION_MARGIN"),
+            TransferType::FundingOption => String::from("FUNDING_OPTION"),
+            TransferType::OptionFunding => String::from("OPTION_FUNDING"),
+            TransferType::MainPortfolioMargin => String::from("MAIN_PORTFOLIO_MARGIN"),
+            TransferType::PortfolioMarginMain => String::from("PORTFOLIO_MARGIN_MAIN"),
+        }
+    }
+}
+
+/// Account that dust is converted from.
+pub enum DustAccountType {
+    Spot,
+    Margin,
+}
+
+impl From<DustAccountType> for String {
+    fn from(item: DustAccountType) -> Self {
+        match item {
+            DustAccountType::Spot => String::from("SPOT"),
+            DustAccountType::Margin => String::from("MARGIN"),
+        }
+    }
+}
+
+pub struct WithdrawRequest {
+    pub coin: String,
+    /// Default network of the coin if not set.
+    pub network: Option<String>,
+    pub address: String,
+    /// Secondary address identifier (memo/tag) required by some coins.
+    pub address_tag: Option<String>,
+    pub amount: f64,
+    /// Client id for the withdrawal.
+    pub withdraw_order_id: Option<String>,
+    pub wallet_type: Option<WalletType>,
+}
+
+/// Destination addresses that withdrawals are allowed to be sent to.
+///
+/// Used by `withdraw_checked`, a coin/network pair with no allowed address rejects every withdrawal.
+#[derive(Debug, Clone, Default)]
+pub struct WithdrawAllowList {
+    addresses: Vec<AllowedAddress>,
+}
+
+#[derive(Debug, Clone)]
+struct AllowedAddress {
+    coin: String,
+    network: String,
+    address: String,
+    address_tag: Option<String>,
+}
+
+impl WithdrawAllowList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allows withdrawals of coin on network to address (and address_tag, if set).
+    pub fn allow<S1, S2, S3>(
+        mut self, coin: S1, network: S2, address: S3, address_tag: Option<String>,
+    ) -> Self
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<String>,
+    {
+        self.addresses.push(AllowedAddress {
+            coin: coin.into(),
+            network: network.into(),
+            address: address.into(),
+            address_tag,
+        });
+        self
+    }
+
+    pub fn is_allowed(
+        &self, coin: &str, network: &str, address: &str, address_tag: Option<&str>,
+    ) -> bool {
+        self.addresses.iter().any(|allowed| {
+            allowed.coin == coin
+                && allowed.network == network
+                && allowed.address == address
+                && allowed.address_tag.as_deref() == address_tag
+        })
+    }
+}
+
+/// Checks a withdrawal against the allow list and the limits of the coin's networks,
+/// as returned by `get_all_coins`.
+pub fn validate_withdraw(
+    request: &WithdrawRequest, coin: &CoinInfo, allow_list: &WithdrawAllowList,
+) -> Result<()> {
+    if coin.coin != request.coin {
+        bail!(format!(
+            "Coin mismatch: {} requested, {} given",
+            request.coin, coin.coin
+        ));
+    }
+    if !coin.withdraw_all_enable {
+        bail!(format!("Withdrawals of {} are disabled", coin.coin));
+    }
+
+    let network = match &request.network {
+        Some(name) => coin.network_list.iter().find(|n| &n.network == name),
+        None => coin.network_list.iter().find(|n| n.is_default),
+    };
+    let network = match network {
+        Some(network) => network,
+        None => bail!(format!("Network not found for {}", coin.coin)),
+    };
+    if !network.withdraw_enable {
+        bail!(format!(
+            "Withdrawals of {} on {} are disabled",
+            coin.coin, network.network
+        ));
+    }
+
+    let address_tag = request.address_tag.as_deref();
+    if !allow_list.is_allowed(&coin.coin, &network.network, &request.address, address_tag) {
+        bail!(format!(
+            "Address {} is not allowed for {} on {}",
+            request.address, coin.coin, network.network
+        ));
+    }
+
+    if !matches_regex(&network.address_regex, &request.address)? {
+        bail!(format!(
+            "Invalid {} address: {}",
+            network.network, request.address
+        ));
+    }
+    if let Some(tag) = address_tag {
+        if !matches_regex(&network.memo_regex, tag)? {
+            bail!(format!("Invalid {} address tag: {}", network.network, tag));
+        }
+    }
+
+    if request.amount < network.withdraw_min {
+        bail!(format!(
+            "Amount {} is below the minimum withdrawal of {}",
+            request.amount, network.withdraw_min
+        ));
+    }
+    if let Some(multiple) = &network.withdraw_integer_multiple {
+        let multiple: f64 = multiple.parse()?;
+        if multiple > 0.0 {
+            let steps = request.amount / multiple;
+            if (steps - steps.round()).abs() > 1e-6 {
+                bail!(format!(
+                    "Amount {} is not a multiple of {}",
+                    request.amount, multiple
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// An empty regex means the network has no constraint
+fn matches_regex(pattern: &str, value: &str) -> Result<bool> {
+    if pattern.is_empty() {
+        return Ok(true);
+    }
+    match Regex::new(pattern) {
+        Ok(regex) => Ok(regex.is_match(value)),
+        Err(e) => bail!(format!("Invalid regex {}: {}", pattern, e)),
+    }
+}
+
 impl Savings {
     /// Get all coins available for deposit and withdrawal
     pub fn get_all_coins(&self) -> Result<Vec<CoinInfo>> {
@@ -48,4 +315,477 @@ impl Savings {
         self.client
             .get_signed(API::Savings(Sapi::DepositAddress), Some(request))
     }
+
+    /// Submit a withdrawal.
+    ///
+    /// No client-side checks are done, see `withdraw_checked`.
+    pub fn withdraw(&self, request: WithdrawRequest) -> Result<WithdrawId> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("coin".into(), request.coin);
+        if let Some(network) = request.network {
+            parameters.insert("network".into(), network);
+        }
+        parameters.insert("address".into(), encode_param(&request.address));
+        if let Some(address_tag) = request.address_tag {
+            parameters.insert("addressTag".into(), encode_param(&address_tag));
+        }
+        parameters.insert("amount".into(), request.amount.to_string());
+        if let Some(withdraw_order_id) = request.withdraw_order_id {
+            parameters.insert("withdrawOrderId".into(), encode_param(&withdraw_order_id));
+        }
+        if let Some(wallet_type) = request.wallet_type {
+            parameters.insert("walletType".into(), wallet_type.into());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::Withdraw), request)
+    }
+
+    /// Submit a withdrawal after validating it with `validate_withdraw`.
+    ///
+    /// The coin's networks are fetched with `get_all_coins`, nothing is sent if validation fails.
+    pub fn withdraw_checked(
+        &self, request: WithdrawRequest, allow_list: &WithdrawAllowList,
+    ) -> Result<WithdrawId> {
+        let coins = self.get_all_coins()?;
+        let coin = match coins.iter().find(|coin| coin.coin == request.coin) {
+            Some(coin) => coin,
+            None => bail!(format!("Coin not found: {}", request.coin)),
+        };
+        validate_withdraw(&request, coin, allow_list)?;
+        self.withdraw(request)
+    }
+
+    /// Deposit history, within the last 90 days when start_time and end_time are omitted.
+    ///
+    /// The range between start_time and end_time must not exceed 90 days, see `deposit_history_range`.
+    pub fn get_deposit_history<S1, S2, S3, S4, S5, S6>(
+        &self, coin: S1, status: S2, start_time: S3, end_time: S4, offset: S5, limit: S6,
+    ) -> Result<Vec<DepositRecord>>
+    where
+        S1: Into<Option<String>>,
+        S2: Into<Option<DepositStatus>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u32>>,
+        S6: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(status) = status.into() {
+            parameters.insert("status".into(), u8::from(status).to_string());
+        }
+        Self::insert_history_parameters(
+            &mut parameters,
+            coin.into(),
+            start_time.into(),
+            end_time.into(),
+            offset.into(),
+            limit.into(),
+        );
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::DepositHistory), Some(request))
+    }
+
+    /// Withdraw history, within the last 90 days when start_time and end_time are omitted.
+    ///
+    /// The range between start_time and end_time must not exceed 90 days, see `withdraw_history_range`.
+    pub fn get_withdraw_history<S1, S2, S3, S4, S5, S6>(
+        &self, coin: S1, status: S2, start_time: S3, end_time: S4, offset: S5, limit: S6,
+    ) -> Result<Vec<WithdrawRecord>>
+    where
+        S1: Into<Option<String>>,
+        S2: Into<Option<WithdrawStatus>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u32>>,
+        S6: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(status) = status.into() {
+            parameters.insert("status".into(), u8::from(status).to_string());
+        }
+        Self::insert_history_parameters(
+            &mut parameters,
+            coin.into(),
+            start_time.into(),
+            end_time.into(),
+            offset.into(),
+            limit.into(),
+        );
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::WithdrawHistory), Some(request))
+    }
+
+    /// Withdrawals submitted with the given `withdraw_order_id`.
+    pub fn get_withdraw_history_by_order_id<S>(
+        &self, withdraw_order_id: S,
+    ) -> Result<Vec<WithdrawRecord>>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("withdrawOrderId".into(), withdraw_order_id.into());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::WithdrawHistory), Some(request))
+    }
+
+    /// Walks all deposits between start_time and end_time, in 90-day windows and pages of 1000.
+    pub fn deposit_history_range(
+        &self, coin: Option<String>, start_time: u64, end_time: u64,
+    ) -> CapitalHistory<'_, DepositRecord> {
+        Pages::new(CapitalPages::new(
+            self,
+            coin,
+            start_time,
+            end_time,
+            |savings, coin, start_time, end_time, offset| {
+                savings.get_deposit_history(
+                    coin,
+                    None,
+                    start_time,
+                    end_time,
+                    offset,
+                    CAPITAL_HISTORY_PAGE_LIMIT,
+                )
+            },
+        ))
+    }
+
+    /// Walks all withdrawals between start_time and end_time, in 90-day windows and pages of 1000.
+    pub fn withdraw_history_range(
+        &self, coin: Option<String>, start_time: u64, end_time: u64,
+    ) -> CapitalHistory<'_, WithdrawRecord> {
+        Pages::new(CapitalPages::new(
+            self,
+            coin,
+            start_time,
+            end_time,
+            |savings, coin, start_time, end_time, offset| {
+                savings.get_withdraw_history(
+                    coin,
+                    None,
+                    start_time,
+                    end_time,
+                    offset,
+                    CAPITAL_HISTORY_PAGE_LIMIT,
+                )
+            },
+        ))
+    }
+
+    /// Transfer an asset between wallets.
+    ///
+    /// from_symbol and to_symbol are required for transfers from and to isolated margin accounts.
+    pub fn universal_transfer<S, F>(
+        &self, transfer_type: TransferType, asset: S, amount: F, from_symbol: Option<String>,
+        to_symbol: Option<String>,
+    ) -> Result<TransactionId>
+    where
+        S: Into<String>,
+        F: Into<f64>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("type".into(), transfer_type.into());
+        parameters.insert("asset".into(), asset.into());
+        parameters.insert("amount".into(), amount.into().to_string());
+        if let Some(from_symbol) = from_symbol {
+            parameters.insert("fromSymbol".into(), from_symbol);
+        }
+        if let Some(to_symbol) = to_symbol {
+            parameters.insert("toSymbol".into(), to_symbol);
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::UniversalTransfer), request)
+    }
+
+    /// Universal transfer history, within the last 6 months when start_time is omitted.
+    ///
+    /// current is the page number (starting at 1) and size the page size (max 100).
+    #[allow(clippy::too_many_arguments)]
+    pub fn get_universal_transfer_history<S1, S2, S3, S4>(
+        &self, transfer_type: TransferType, start_time: S1, end_time: S2, current: S3, size: S4,
+        from_symbol: Option<String>, to_symbol: Option<String>,
+    ) -> Result<UniversalTransferHistory>
+    where
+        S1: Into<Option<u64>>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u32>>,
+        S4: Into<Option<u32>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("type".into(), transfer_type.into());
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), st.to_string());
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), et.to_string());
+        }
+        if let Some(current) = current.into() {
+            parameters.insert("current".into(), current.to_string());
+        }
+        if let Some(size) = size.into() {
+            parameters.insert("size".into(), size.to_string());
+        }
+        if let Some(from_symbol) = from_symbol {
+            parameters.insert("fromSymbol".into(), from_symbol);
+        }
+        if let Some(to_symbol) = to_symbol {
+            parameters.insert("toSymbol".into(), to_symbol);
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::UniversalTransfer), Some(request))
+    }
+
+    /// Assets that can be converted to BNB.
+    pub fn get_dust_assets(&self, account_type: Option<DustAccountType>) -> Result<DustAssets> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(account_type) = account_type {
+            parameters.insert("accountType".into(), account_type.into());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::DustBtc), request)
+    }
+
+    /// Convert dust assets to BNB.
+    pub fn dust_transfer<S>(
+        &self, assets: &[S], account_type: Option<DustAccountType>,
+    ) -> Result<DustTransfer>
+    where
+        S: AsRef<str>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(account_type) = account_type {
+            parameters.insert("accountType".into(), account_type.into());
+        }
+
+        // asset is repeated once per asset, which a BTreeMap cannot hold
+        let assets: Vec<String> = assets
+            .iter()
+            .map(|asset| format!("asset={}", asset.as_ref()))
+            .collect();
+        let request = format!(
+            "{}&{}",
+            assets.join("&"),
+            build_signed_request(parameters, self.recv_window)?
+        );
+        self.client.post_signed(API::Savings(Sapi::Dust), request)
+    }
+
+    /// Dust conversion history (only conversions after 2020/12/01 are returned).
+    pub fn get_dust_log<S1, S2>(&self, start_time: S1, end_time: S2) -> Result<DustLog>
+    where
+        S1: Into<Option<u64>>,
+        S2: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), st.to_string());
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), et.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::DustLog), Some(request))
+    }
+
+    /// Asset dividend records, within the last 6 months when start_time is omitted (max limit 500).
+    pub fn get_asset_dividends<S1, S2, S3, S4>(
+        &self, asset: S1, start_time: S2, end_time: S3, limit: S4,
+    ) -> Result<AssetDividends>
+    where
+        S1: Into<Option<String>>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(asset) = asset.into() {
+            parameters.insert("asset".into(), asset);
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), st.to_string());
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), et.to_string());
+        }
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), lt.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::AssetDividend), Some(request))
+    }
+
+    /// Trade fees of a symbol, or of all symbols if no symbol is given.
+    pub fn get_trade_fees(&self, symbol: Option<String>) -> Result<Vec<TradeFee>> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(symbol) = symbol {
+            parameters.insert("symbol".into(), symbol);
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::TradeFee), Some(request))
+    }
+
+    /// Funding wallet balances, of all assets if no asset is given.
+    pub fn get_funding_assets(
+        &self, asset: Option<String>, need_btc_valuation: bool,
+    ) -> Result<Vec<AssetBalance>> {
+        let parameters = Self::asset_balance_parameters(asset, need_btc_valuation);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::FundingAsset), request)
+    }
+
+    /// Positive balances of the user assets, of all assets if no asset is given.
+    pub fn get_user_assets(
+        &self, asset: Option<String>, need_btc_valuation: bool,
+    ) -> Result<Vec<AssetBalance>> {
+        let parameters = Self::asset_balance_parameters(asset, need_btc_valuation);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::UserAsset), request)
+    }
+
+    /// Permissions of the API key.
+    pub fn get_api_restrictions(&self) -> Result<ApiRestrictions> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::ApiRestrictions), Some(request))
+    }
+
+    pub fn get_account_status(&self) -> Result<AccountStatus> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::AccountStatus), Some(request))
+    }
+
+    /// Whether API trading is locked by the trading rules indicators.
+    pub fn get_api_trading_status(&self) -> Result<ApiTradingStatus> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::ApiTradingStatus), Some(request))
+    }
+
+    fn asset_balance_parameters(
+        asset: Option<String>, need_btc_valuation: bool,
+    ) -> BTreeMap<String, String> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(asset) = asset {
+            parameters.insert("asset".into(), asset);
+        }
+        if need_btc_valuation {
+            parameters.insert("needBtcValuation".into(), "true".into());
+        }
+        parameters
+    }
+
+    fn insert_history_parameters(
+        parameters: &mut BTreeMap<String, String>, coin: Option<String>, start_time: Option<u64>,
+        end_time: Option<u64>, offset: Option<u32>, limit: Option<u16>,
+    ) {
+        if let Some(coin) = coin {
+            parameters.insert("coin".into(), coin);
+        }
+        if let Some(st) = start_time {
+            parameters.insert("startTime".into(), st.to_string());
+        }
+        if let Some(et) = end_time {
+            parameters.insert("endTime".into(), et.to_string());
+        }
+        if let Some(offset) = offset {
+            parameters.insert("offset".into(), offset.to_string());
+        }
+        if let Some(lt) = limit {
+            parameters.insert("limit".into(), lt.to_string());
+        }
+    }
+}
+
+type HistoryPage<T> = fn(&Savings, Option<String>, u64, u64, u32) -> Result<Vec<T>>;
+
+/// Iterator over the deposit or withdraw history of an arbitrary time range.
+pub type CapitalHistory<'a, T> = Pages<CapitalPages<'a, T>>;
+
+/// Pages of a `CapitalHistory`.
+///
+/// The range is split into windows accepted by the API, each window is read page by page.
+pub struct CapitalPages<'a, T> {
+    savings: &'a Savings,
+    coin: Option<String>,
+    window_start: u64,
+    end_time: u64,
+    offset: u32,
+    fetch_page: HistoryPage<T>,
+    finished: bool,
+}
+
+impl<'a, T> CapitalPages<'a, T> {
+    fn new(
+        savings: &'a Savings, coin: Option<String>, start_time: u64, end_time: u64,
+        fetch_page: HistoryPage<T>,
+    ) -> Self {
+        CapitalPages {
+            savings,
+            coin,
+            window_start: start_time,
+            end_time,
+            offset: 0,
+            fetch_page,
+            finished: start_time > end_time,
+        }
+    }
+}
+
+impl<'a, T> PageSource for CapitalPages<'a, T> {
+    type Item = T;
+
+    fn fetch_page(&mut self) -> Result<Vec<T>> {
+        let window_end = self.end_time.min(
+            self.window_start
+                .saturating_add(CAPITAL_HISTORY_MAX_WINDOW - 1),
+        );
+        let records = (self.fetch_page)(
+            self.savings,
+            self.coin.clone(),
+            self.window_start,
+            window_end,
+            self.offset,
+        )?;
+
+        // A short page means the window is exhausted, move on to the next one
+        if records.len() < usize::from(CAPITAL_HISTORY_PAGE_LIMIT) {
+            self.offset = 0;
+            if window_end >= self.end_time {
+                self.finished = true;
+            } else {
+                self.window_start = window_end + 1;
+            }
+        } else {
+            self.offset += records.len() as u32;
+        }
+
+        Ok(records)
+    }
+
+    fn finished(&self) -> bool {
+        self.finished
+    }
 }
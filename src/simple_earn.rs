@@ -0,0 +1,472 @@
+use crate::util::*;
+use crate::model::*;
+use crate::client::*;
+use crate::errors::*;
+use crate::preflight::{ApiPermission, RequiredPermissions};
+use std::collections::BTreeMap;
+use crate::api::API;
+use crate::api::Sapi;
+
+/// Largest page returned by the Simple Earn list and history requests.
+pub const EARN_PAGE_SIZE: u32 = 100;
+
+#[derive(Clone)]
+pub struct SimpleEarn {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl RequiredPermissions for SimpleEarn {
+    fn required_permissions() -> Vec<ApiPermission> {
+        vec![ApiPermission::Reading]
+    }
+}
+
+/// Wallet funds are taken from or sent to.
+///
+/// All is only accepted when subscribing.
+pub enum EarnAccount {
+    Spot,
+    Fund,
+    All,
+}
+
+impl From<EarnAccount> for String {
+    fn from(item: EarnAccount) -> Self {
+        match item {
+            EarnAccount::Spot => String::from("SPOT"),
+            EarnAccount::Fund => String::from("FUND"),
+            EarnAccount::All => String::from("ALL"),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum FlexibleRewardType {
+    Bonus,
+    Realtime,
+    Rewards,
+}
+
+impl From<FlexibleRewardType> for String {
+    fn from(item: FlexibleRewardType) -> Self {
+        match item {
+            FlexibleRewardType::Bonus => String::from("BONUS"),
+            FlexibleRewardType::Realtime => String::from("REALTIME"),
+            FlexibleRewardType::Rewards => String::from("REWARDS"),
+        }
+    }
+}
+
+/// Filters of the Simple Earn history requests.
+///
+/// Each request only sends the filters it supports. The range between start_time and
+/// end_time must not exceed 90 days, the last 90 days are returned when both are omitted.
+#[derive(Clone, Default)]
+pub struct EarnHistoryQuery {
+    pub asset: Option<String>,
+    /// Flexible product id
+    pub product_id: Option<String>,
+    /// Locked product id
+    pub project_id: Option<String>,
+    pub position_id: Option<u64>,
+    pub purchase_id: Option<u64>,
+    pub redeem_id: Option<u64>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+}
+
+impl EarnHistoryQuery {
+    fn parameters(&self, filters: &[&str], current: u32, size: u32) -> BTreeMap<String, String> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        let values = [
+            ("asset", self.asset.clone()),
+            ("productId", self.product_id.clone()),
+            ("projectId", self.project_id.clone()),
+            ("positionId", self.position_id.map(|id| id.to_string())),
+            ("purchaseId", self.purchase_id.map(|id| id.to_string())),
+            ("redeemId", self.redeem_id.map(|id| id.to_string())),
+        ];
+        for (name, value) in values.iter() {
+            if let Some(value) = value {
+                if filters.contains(name) {
+                    parameters.insert(name.to_string(), value.clone());
+                }
+            }
+        }
+        if let Some(st) = self.start_time {
+            parameters.insert("startTime".into(), st.to_string());
+        }
+        if let Some(et) = self.end_time {
+            parameters.insert("endTime".into(), et.to_string());
+        }
+        parameters.insert("current".into(), current.to_string());
+        parameters.insert("size".into(), size.to_string());
+        parameters
+    }
+}
+
+impl SimpleEarn {
+    /// Flexible products, of all assets if no asset is given.
+    pub fn get_flexible_products<S1, S2, S3>(
+        &self, asset: S1, current: S2, size: S3,
+    ) -> Result<EarnPage<FlexibleProduct>>
+    where
+        S1: Into<Option<String>>,
+        S2: Into<Option<u32>>,
+        S3: Into<Option<u32>>,
+    {
+        let parameters = Self::list_parameters(asset.into(), current.into(), size.into());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::SimpleEarnFlexibleList), Some(request))
+    }
+
+    /// Locked products, of all assets if no asset is given.
+    pub fn get_locked_products<S1, S2, S3>(
+        &self, asset: S1, current: S2, size: S3,
+    ) -> Result<EarnPage<LockedProduct>>
+    where
+        S1: Into<Option<String>>,
+        S2: Into<Option<u32>>,
+        S3: Into<Option<u32>>,
+    {
+        let parameters = Self::list_parameters(asset.into(), current.into(), size.into());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::SimpleEarnLockedList), Some(request))
+    }
+
+    pub fn subscribe_flexible<S>(
+        &self, product_id: S, amount: f64, auto_subscribe: Option<bool>,
+        source_account: Option<EarnAccount>,
+    ) -> Result<EarnSubscription>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("productId".into(), product_id.into());
+        Self::insert_subscription_parameters(
+            &mut parameters,
+            amount,
+            auto_subscribe,
+            source_account,
+        );
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::SimpleEarnFlexibleSubscribe), request)
+    }
+
+    pub fn subscribe_locked<S>(
+        &self, project_id: S, amount: f64, auto_subscribe: Option<bool>,
+        source_account: Option<EarnAccount>,
+    ) -> Result<EarnSubscription>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("projectId".into(), project_id.into());
+        Self::insert_subscription_parameters(
+            &mut parameters,
+            amount,
+            auto_subscribe,
+            source_account,
+        );
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::SimpleEarnLockedSubscribe), request)
+    }
+
+    /// Redeem a flexible product, the whole position if amount is None.
+    pub fn redeem_flexible<S>(
+        &self, product_id: S, amount: Option<f64>, dest_account: Option<EarnAccount>,
+    ) -> Result<EarnRedemption>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("productId".into(), product_id.into());
+        match amount {
+            Some(amount) => parameters.insert("amount".into(), amount.to_string()),
+            None => parameters.insert("redeemAll".into(), "true".into()),
+        };
+        if let Some(dest_account) = dest_account {
+            parameters.insert("destAccount".into(), dest_account.into());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::SimpleEarnFlexibleRedeem), request)
+    }
+
+    /// Redeem a locked position before its end date.
+    pub fn redeem_locked(&self, position_id: u64) -> Result<EarnRedemption> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("positionId".into(), position_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::SimpleEarnLockedRedeem), request)
+    }
+
+    pub fn get_flexible_positions<S1, S2, S3, S4>(
+        &self, asset: S1, product_id: S2, current: S3, size: S4,
+    ) -> Result<EarnPage<FlexiblePosition>>
+    where
+        S1: Into<Option<String>>,
+        S2: Into<Option<String>>,
+        S3: Into<Option<u32>>,
+        S4: Into<Option<u32>>,
+    {
+        let mut parameters = Self::list_parameters(asset.into(), current.into(), size.into());
+        if let Some(product_id) = product_id.into() {
+            parameters.insert("productId".into(), product_id);
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client.get_signed(
+            API::Savings(Sapi::SimpleEarnFlexiblePosition),
+            Some(request),
+        )
+    }
+
+    pub fn get_locked_positions<S1, S2, S3, S4, S5>(
+        &self, asset: S1, position_id: S2, project_id: S3, current: S4, size: S5,
+    ) -> Result<EarnPage<LockedPosition>>
+    where
+        S1: Into<Option<String>>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<String>>,
+        S4: Into<Option<u32>>,
+        S5: Into<Option<u32>>,
+    {
+        let mut parameters = Self::list_parameters(asset.into(), current.into(), size.into());
+        if let Some(position_id) = position_id.into() {
+            parameters.insert("positionId".into(), position_id.to_string());
+        }
+        if let Some(project_id) = project_id.into() {
+            parameters.insert("projectId".into(), project_id);
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::SimpleEarnLockedPosition), Some(request))
+    }
+
+    pub fn get_flexible_subscription_records(
+        &self, query: &EarnHistoryQuery, current: u32, size: u32,
+    ) -> Result<EarnPage<FlexibleSubscriptionRecord>> {
+        let parameters = query.parameters(&["asset", "productId", "purchaseId"], current, size);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client.get_signed(
+            API::Savings(Sapi::SimpleEarnFlexibleSubscriptionRecord),
+            Some(request),
+        )
+    }
+
+    pub fn get_locked_subscription_records(
+        &self, query: &EarnHistoryQuery, current: u32, size: u32,
+    ) -> Result<EarnPage<LockedSubscriptionRecord>> {
+        let parameters = query.parameters(&["asset", "purchaseId"], current, size);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client.get_signed(
+            API::Savings(Sapi::SimpleEarnLockedSubscriptionRecord),
+            Some(request),
+        )
+    }
+
+    pub fn get_flexible_redemption_records(
+        &self, query: &EarnHistoryQuery, current: u32, size: u32,
+    ) -> Result<EarnPage<FlexibleRedemptionRecord>> {
+        let parameters = query.parameters(&["asset", "productId", "redeemId"], current, size);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client.get_signed(
+            API::Savings(Sapi::SimpleEarnFlexibleRedemptionRecord),
+            Some(request),
+        )
+    }
+
+    pub fn get_locked_redemption_records(
+        &self, query: &EarnHistoryQuery, current: u32, size: u32,
+    ) -> Result<EarnPage<LockedRedemptionRecord>> {
+        let parameters = query.parameters(&["asset", "positionId", "redeemId"], current, size);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client.get_signed(
+            API::Savings(Sapi::SimpleEarnLockedRedemptionRecord),
+            Some(request),
+        )
+    }
+
+    pub fn get_flexible_rewards_records(
+        &self, query: &EarnHistoryQuery, reward_type: FlexibleRewardType, current: u32, size: u32,
+    ) -> Result<EarnPage<FlexibleRewardRecord>> {
+        let mut parameters = query.parameters(&["asset", "productId"], current, size);
+        parameters.insert("type".into(), reward_type.into());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client.get_signed(
+            API::Savings(Sapi::SimpleEarnFlexibleRewardsRecord),
+            Some(request),
+        )
+    }
+
+    pub fn get_locked_rewards_records(
+        &self, query: &EarnHistoryQuery, current: u32, size: u32,
+    ) -> Result<EarnPage<LockedRewardRecord>> {
+        let parameters = query.parameters(&["asset", "positionId"], current, size);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client.get_signed(
+            API::Savings(Sapi::SimpleEarnLockedRewardsRecord),
+            Some(request),
+        )
+    }
+
+    pub fn get_collateral_records(
+        &self, query: &EarnHistoryQuery, current: u32, size: u32,
+    ) -> Result<EarnPage<CollateralRecord>> {
+        let parameters = query.parameters(&["productId"], current, size);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client.get_signed(
+            API::Savings(Sapi::SimpleEarnFlexibleCollateralRecord),
+            Some(request),
+        )
+    }
+
+    /// Walks all flexible subscriptions matching query, page by page.
+    pub fn flexible_subscription_history(
+        &self, query: EarnHistoryQuery,
+    ) -> EarnHistory<'_, FlexibleSubscriptionRecord> {
+        Pages::new(EarnPages::new(move |current| {
+            self.get_flexible_subscription_records(&query, current, EARN_PAGE_SIZE)
+        }))
+    }
+
+    /// Walks all locked subscriptions matching query, page by page.
+    pub fn locked_subscription_history(
+        &self, query: EarnHistoryQuery,
+    ) -> EarnHistory<'_, LockedSubscriptionRecord> {
+        Pages::new(EarnPages::new(move |current| {
+            self.get_locked_subscription_records(&query, current, EARN_PAGE_SIZE)
+        }))
+    }
+
+    /// Walks all flexible redemptions matching query, page by page.
+    pub fn flexible_redemption_history(
+        &self, query: EarnHistoryQuery,
+    ) -> EarnHistory<'_, FlexibleRedemptionRecord> {
+        Pages::new(EarnPages::new(move |current| {
+            self.get_flexible_redemption_records(&query, current, EARN_PAGE_SIZE)
+        }))
+    }
+
+    /// Walks all locked redemptions matching query, page by page.
+    pub fn locked_redemption_history(
+        &self, query: EarnHistoryQuery,
+    ) -> EarnHistory<'_, LockedRedemptionRecord> {
+        Pages::new(EarnPages::new(move |current| {
+            self.get_locked_redemption_records(&query, current, EARN_PAGE_SIZE)
+        }))
+    }
+
+    /// Walks all flexible rewards of reward_type matching query, page by page.
+    pub fn flexible_rewards_history(
+        &self, query: EarnHistoryQuery, reward_type: FlexibleRewardType,
+    ) -> EarnHistory<'_, FlexibleRewardRecord> {
+        Pages::new(EarnPages::new(move |current| {
+            self.get_flexible_rewards_records(&query, reward_type, current, EARN_PAGE_SIZE)
+        }))
+    }
+
+    /// Walks all locked rewards matching query, page by page.
+    pub fn locked_rewards_history(
+        &self, query: EarnHistoryQuery,
+    ) -> EarnHistory<'_, LockedRewardRecord> {
+        Pages::new(EarnPages::new(move |current| {
+            self.get_locked_rewards_records(&query, current, EARN_PAGE_SIZE)
+        }))
+    }
+
+    /// Walks all collateral records matching query, page by page.
+    pub fn collateral_history(&self, query: EarnHistoryQuery) -> EarnHistory<'_, CollateralRecord> {
+        Pages::new(EarnPages::new(move |current| {
+            self.get_collateral_records(&query, current, EARN_PAGE_SIZE)
+        }))
+    }
+
+    fn list_parameters(
+        asset: Option<String>, current: Option<u32>, size: Option<u32>,
+    ) -> BTreeMap<String, String> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(asset) = asset {
+            parameters.insert("asset".into(), asset);
+        }
+        if let Some(current) = current {
+            parameters.insert("current".into(), current.to_string());
+        }
+        if let Some(size) = size {
+            parameters.insert("size".into(), size.to_string());
+        }
+        parameters
+    }
+
+    fn insert_subscription_parameters(
+        parameters: &mut BTreeMap<String, String>, amount: f64, auto_subscribe: Option<bool>,
+        source_account: Option<EarnAccount>,
+    ) {
+        parameters.insert("amount".into(), amount.to_string());
+        if let Some(auto_subscribe) = auto_subscribe {
+            parameters.insert("autoSubscribe".into(), auto_subscribe.to_string());
+        }
+        if let Some(source_account) = source_account {
+            parameters.insert("sourceAccount".into(), source_account.into());
+        }
+    }
+}
+
+type EarnPageFetch<'a, T> = Box<dyn FnMut(u32) -> Result<EarnPage<T>> + 'a>;
+
+/// Iterator over all the rows of a paginated Simple Earn request.
+pub type EarnHistory<'a, T> = Pages<EarnPages<'a, T>>;
+
+/// Pages of an `EarnHistory`.
+///
+/// fetch_page is called with the page number, starting at 1, until `total` rows are read.
+pub struct EarnPages<'a, T> {
+    fetch_page: EarnPageFetch<'a, T>,
+    current: u32,
+    fetched: u64,
+    finished: bool,
+}
+
+impl<'a, T> EarnPages<'a, T> {
+    fn new<F>(fetch_page: F) -> Self
+    where
+        F: FnMut(u32) -> Result<EarnPage<T>> + 'a,
+    {
+        EarnPages {
+            fetch_page: Box::new(fetch_page),
+            current: 1,
+            fetched: 0,
+            finished: false,
+        }
+    }
+}
+
+impl<'a, T> PageSource for EarnPages<'a, T> {
+    type Item = T;
+
+    fn fetch_page(&mut self) -> Result<Vec<T>> {
+        let page = (self.fetch_page)(self.current)?;
+        self.current += 1;
+        self.fetched += page.rows.len() as u64;
+        if page.rows.is_empty() || self.fetched >= page.total {
+            self.finished = true;
+        }
+        Ok(page.rows)
+    }
+
+    fn finished(&self) -> bool {
+        self.finished
+    }
+}
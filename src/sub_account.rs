@@ -0,0 +1,340 @@
+use crate::util::*;
+use crate::model::*;
+use crate::client::*;
+use crate::errors::*;
+use crate::preflight::{ApiPermission, RequiredPermissions};
+use std::collections::BTreeMap;
+use crate::api::API;
+use crate::api::Sapi;
+
+#[derive(Clone)]
+pub struct SubAccount {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl RequiredPermissions for SubAccount {
+    fn required_permissions() -> Vec<ApiPermission> {
+        vec![ApiPermission::Reading]
+    }
+}
+
+/// Wallet of a master or sub-account used by universal transfers.
+pub enum SubAccountType {
+    Spot,
+    UsdtFuture,
+    CoinFuture,
+    Margin,
+    IsolatedMargin,
+}
+
+impl From<SubAccountType> for String {
+    fn from(item: SubAccountType) -> Self {
+        match item {
+            SubAccountType::Spot => String::from("SPOT"),
+            SubAccountType::UsdtFuture => String::from("USDT_FUTURE"),
+            SubAccountType::CoinFuture => String::from("COIN_FUTURE"),
+            SubAccountType::Margin => String::from("MARGIN"),
+            SubAccountType::IsolatedMargin => String::from("ISOLATED_MARGIN"),
+        }
+    }
+}
+
+pub enum FuturesType {
+    UsdM,
+    CoinM,
+}
+
+impl From<FuturesType> for String {
+    fn from(item: FuturesType) -> Self {
+        match item {
+            FuturesType::UsdM => String::from("1"),
+            FuturesType::CoinM => String::from("2"),
+        }
+    }
+}
+
+/// Transfer between two wallets of the master account and/or its sub-accounts.
+///
+/// A missing email stands for the master account.
+pub struct SubAccountTransferRequest {
+    pub from_email: Option<String>,
+    pub to_email: Option<String>,
+    pub from_account_type: SubAccountType,
+    pub to_account_type: SubAccountType,
+    pub asset: String,
+    pub amount: f64,
+    /// Required for transfers from and to ISOLATED_MARGIN
+    pub symbol: Option<String>,
+    pub client_tran_id: Option<String>,
+}
+
+impl SubAccount {
+    /// Sub-accounts of the master account, optionally filtered by email.
+    pub fn get_sub_accounts<S1, S2, S3, S4>(
+        &self, email: S1, is_freeze: S2, page: S3, limit: S4,
+    ) -> Result<SubAccountList>
+    where
+        S1: Into<Option<String>>,
+        S2: Into<Option<bool>>,
+        S3: Into<Option<u32>>,
+        S4: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(email) = email.into() {
+            parameters.insert("email".into(), encode_param(&email));
+        }
+        if let Some(is_freeze) = is_freeze.into() {
+            parameters.insert("isFreeze".into(), is_freeze.to_string());
+        }
+        if let Some(page) = page.into() {
+            parameters.insert("page".into(), page.to_string());
+        }
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), lt.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::SubAccountList), Some(request))
+    }
+
+    /// Create a virtual sub-account, the email is generated from sub_account_string.
+    pub fn create_sub_account<S>(&self, sub_account_string: S) -> Result<SubAccountEmail>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("subAccountString".into(), sub_account_string.into());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::SubAccountCreate), request)
+    }
+
+    // Spot balances of a sub-account
+    pub fn get_assets<S>(&self, email: S) -> Result<SubAccountAssets>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("email".into(), encode_param(&email.into()));
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::SubAccountAssets), Some(request))
+    }
+
+    /// BTC value of the spot assets of the sub-accounts, optionally filtered by email.
+    pub fn get_spot_summary<S1, S2, S3>(
+        &self, email: S1, page: S2, size: S3,
+    ) -> Result<SubAccountSpotSummary>
+    where
+        S1: Into<Option<String>>,
+        S2: Into<Option<u32>>,
+        S3: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(email) = email.into() {
+            parameters.insert("email".into(), encode_param(&email));
+        }
+        if let Some(page) = page.into() {
+            parameters.insert("page".into(), page.to_string());
+        }
+        if let Some(size) = size.into() {
+            parameters.insert("size".into(), size.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::SubAccountSpotSummary), Some(request))
+    }
+
+    /// Futures account summary of all sub-accounts.
+    ///
+    /// Only the summary matching futures_type is set in the response.
+    pub fn get_futures_summary<S1, S2>(
+        &self, futures_type: FuturesType, page: S1, limit: S2,
+    ) -> Result<SubAccountFuturesSummary>
+    where
+        S1: Into<Option<u32>>,
+        S2: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("futuresType".into(), futures_type.into());
+        if let Some(page) = page.into() {
+            parameters.insert("page".into(), page.to_string());
+        }
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), lt.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::SubAccountFuturesSummary), Some(request))
+    }
+
+    /// Transfer between the master account and sub-accounts, or between sub-accounts.
+    pub fn universal_transfer(
+        &self, transfer: SubAccountTransferRequest,
+    ) -> Result<SubAccountTransferId> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(from_email) = transfer.from_email {
+            parameters.insert("fromEmail".into(), encode_param(&from_email));
+        }
+        if let Some(to_email) = transfer.to_email {
+            parameters.insert("toEmail".into(), encode_param(&to_email));
+        }
+        parameters.insert("fromAccountType".into(), transfer.from_account_type.into());
+        parameters.insert("toAccountType".into(), transfer.to_account_type.into());
+        parameters.insert("asset".into(), transfer.asset);
+        parameters.insert("amount".into(), transfer.amount.to_string());
+        if let Some(symbol) = transfer.symbol {
+            parameters.insert("symbol".into(), symbol);
+        }
+        if let Some(client_tran_id) = transfer.client_tran_id {
+            parameters.insert("clientTranId".into(), encode_param(&client_tran_id));
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::SubAccountUniversalTransfer), request)
+    }
+
+    /// Universal transfer history, within the last 30 days when start_time is omitted.
+    #[allow(clippy::too_many_arguments)]
+    pub fn get_universal_transfer_history<S1, S2, S3, S4, S5, S6, S7>(
+        &self, from_email: S1, to_email: S2, client_tran_id: S3, start_time: S4, end_time: S5,
+        page: S6, limit: S7,
+    ) -> Result<SubAccountTransferHistory>
+    where
+        S1: Into<Option<String>>,
+        S2: Into<Option<String>>,
+        S3: Into<Option<String>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+        S6: Into<Option<u32>>,
+        S7: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(from_email) = from_email.into() {
+            parameters.insert("fromEmail".into(), encode_param(&from_email));
+        }
+        if let Some(to_email) = to_email.into() {
+            parameters.insert("toEmail".into(), encode_param(&to_email));
+        }
+        if let Some(client_tran_id) = client_tran_id.into() {
+            parameters.insert("clientTranId".into(), encode_param(&client_tran_id));
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), st.to_string());
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), et.to_string());
+        }
+        if let Some(page) = page.into() {
+            parameters.insert("page".into(), page.to_string());
+        }
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), lt.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client.get_signed(
+            API::Savings(Sapi::SubAccountUniversalTransfer),
+            Some(request),
+        )
+    }
+
+    // Enable futures trading for a sub-account
+    pub fn enable_futures<S>(&self, email: S) -> Result<SubAccountFuturesStatus>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("email".into(), encode_param(&email.into()));
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::SubAccountFuturesEnable), request)
+    }
+
+    // Enable margin trading for a sub-account
+    pub fn enable_margin<S>(&self, email: S) -> Result<SubAccountMarginStatus>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("email".into(), encode_param(&email.into()));
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::SubAccountMarginEnable), request)
+    }
+
+    /// IP restriction of a sub-account API key.
+    pub fn get_ip_restriction<S1, S2>(
+        &self, email: S1, sub_account_api_key: S2,
+    ) -> Result<SubAccountIpRestriction>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("email".into(), encode_param(&email.into()));
+        parameters.insert("subAccountApiKey".into(), sub_account_api_key.into());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::SubAccountIpRestriction), Some(request))
+    }
+
+    /// Restrict a sub-account API key to ip_addresses, or lift the restriction if empty.
+    pub fn set_ip_restriction<S1, S2, S3>(
+        &self, email: S1, sub_account_api_key: S2, ip_addresses: &[S3],
+    ) -> Result<SubAccountIpRestriction>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: AsRef<str>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("email".into(), encode_param(&email.into()));
+        parameters.insert("subAccountApiKey".into(), sub_account_api_key.into());
+        if ip_addresses.is_empty() {
+            // 1: IP unrestricted, 2: restrict access to trusted IPs only
+            parameters.insert("status".into(), "1".into());
+        } else {
+            parameters.insert("status".into(), "2".into());
+            parameters.insert("ipAddress".into(), join_ip_addresses(ip_addresses));
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::SubAccountIpRestrictionV2), request)
+    }
+
+    /// Remove ip_addresses from the IP restriction of a sub-account API key.
+    pub fn delete_ip_restriction<S1, S2, S3>(
+        &self, email: S1, sub_account_api_key: S2, ip_addresses: &[S3],
+    ) -> Result<SubAccountIpRestriction>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: AsRef<str>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("email".into(), encode_param(&email.into()));
+        parameters.insert("subAccountApiKey".into(), sub_account_api_key.into());
+        parameters.insert("ipAddress".into(), join_ip_addresses(ip_addresses));
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Savings(Sapi::SubAccountIpList), Some(request))
+    }
+}
+
+fn join_ip_addresses<S: AsRef<str>>(ip_addresses: &[S]) -> String {
+    let ip_addresses: Vec<&str> = ip_addresses.iter().map(|ip| ip.as_ref()).collect();
+    ip_addresses.join(",")
+}
@@ -1,6 +1,7 @@
 use crate::model::*;
 use crate::client::*;
 use crate::errors::*;
+use crate::preflight::{ApiPermission, RequiredPermissions};
 use crate::api::API;
 use crate::api::Spot;
 
@@ -10,6 +11,12 @@ pub struct UserStream {
     pub recv_window: u64,
 }
 
+impl RequiredPermissions for UserStream {
+    fn required_permissions() -> Vec<ApiPermission> {
+        vec![ApiPermission::Reading]
+    }
+}
+
 impl UserStream {
     // User Stream
     pub fn start(&self) -> Result<UserDataStream> {
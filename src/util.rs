@@ -1,6 +1,7 @@
 use crate::errors::*;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, VecDeque};
 use std::time::{SystemTime, UNIX_EPOCH};
+use serde::Serialize;
 use serde_json::Value;
 
 pub fn build_request(parameters: BTreeMap<String, String>) -> String {
@@ -13,6 +14,27 @@ pub fn build_request(parameters: BTreeMap<String, String>) -> String {
     request
 }
 
+// Percent-encodes a free-form value, so that `&` or `=` in it can't add parameters to the request
+pub fn encode_param(value: &str) -> String {
+    url::form_urlencoded::byte_serialize(value.as_bytes()).collect()
+}
+
+// Builds the value of a `symbols` parameter, e.g. ["BTCUSDT","BNBUSDT"] (URL encoded)
+pub fn build_symbols_param<S: AsRef<str>>(symbols: &[S]) -> String {
+    let symbols: Vec<String> = symbols
+        .iter()
+        .map(|symbol| format!("\"{}\"", symbol.as_ref()))
+        .collect();
+    let value = format!("[{}]", symbols.join(","));
+    url::form_urlencoded::byte_serialize(value.as_bytes()).collect()
+}
+
+// Builds the value of a JSON list parameter, e.g. `orders` of a batch order (URL encoded)
+pub fn build_json_list_param<T: Serialize>(items: &[T]) -> Result<String> {
+    let value = serde_json::to_string(items)?;
+    Ok(url::form_urlencoded::byte_serialize(value.as_bytes()).collect())
+}
+
 pub fn build_signed_request(
     parameters: BTreeMap<String, String>, recv_window: u64,
 ) -> Result<String> {
@@ -44,3 +66,50 @@ fn get_timestamp(start: SystemTime) -> Result<u64> {
     let since_epoch = start.duration_since(UNIX_EPOCH)?;
     Ok(since_epoch.as_secs() * 1000 + u64::from(since_epoch.subsec_nanos()) / 1_000_000)
 }
+
+/// Cursor of a paginated request, read page by page by `Pages`.
+pub trait PageSource {
+    type Item;
+
+    /// Fetches the next page and moves the cursor past it.
+    fn fetch_page(&mut self) -> Result<Vec<Self::Item>>;
+
+    /// Whether the last page was fetched.
+    fn finished(&self) -> bool;
+}
+
+/// Iterator over the records of a paginated request, pages are fetched lazily.
+///
+/// Iteration stops after the first error.
+pub struct Pages<S: PageSource> {
+    source: S,
+    buffer: VecDeque<S::Item>,
+    failed: bool,
+}
+
+impl<S: PageSource> Pages<S> {
+    pub(crate) fn new(source: S) -> Self {
+        Pages {
+            source,
+            buffer: VecDeque::new(),
+            failed: false,
+        }
+    }
+}
+
+impl<S: PageSource> Iterator for Pages<S> {
+    type Item = Result<S::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.buffer.is_empty() && !self.failed && !self.source.finished() {
+            match self.source.fetch_page() {
+                Ok(items) => self.buffer.extend(items),
+                Err(e) => {
+                    self.failed = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+        self.buffer.pop_front().map(Ok)
+    }
+}
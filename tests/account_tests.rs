@@ -854,4 +854,229 @@ mod tests {
         assert_eq!(history.is_maker, false);
         assert_eq!(history.is_best_match, true);
     }
+
+    #[test]
+    fn get_account_non_zero_balances() {
+        let mock_get_account = mock("GET", "/api/v3/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "omitZeroBalances=true&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/get_account.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let account = account.get_account_non_zero_balances().unwrap();
+
+        mock_get_account.assert();
+
+        assert_eq!(account.balances.len(), 2);
+        assert!(account.commission_rates.is_none());
+    }
+
+    #[test]
+    fn get_commission_rates() {
+        let mock_get_commission_rates = mock("GET", "/api/v3/account/commission")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/get_commission_rates.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let commission = account.get_commission_rates("BTCUSDT").unwrap();
+
+        mock_get_commission_rates.assert();
+
+        assert_eq!(commission.symbol, "BTCUSDT");
+        assert!(approx_eq!(
+            f64,
+            commission.standard_commission.taker,
+            0.0000002,
+            ulps = 2
+        ));
+        assert!(approx_eq!(
+            f64,
+            commission.tax_commission.maker,
+            0.00000112,
+            ulps = 2
+        ));
+        assert!(commission.discount.enabled_for_account);
+        assert_eq!(commission.discount.discount_asset, "BNB");
+        assert!(approx_eq!(
+            f64,
+            commission.discount.discount,
+            0.75,
+            ulps = 2
+        ));
+    }
+
+    #[test]
+    fn get_unfilled_order_count() {
+        let mock_get_unfilled_order_count = mock("GET", "/api/v3/rateLimit/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/get_unfilled_order_count.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let counts = account.get_unfilled_order_count().unwrap();
+
+        mock_get_unfilled_order_count.assert();
+
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts[0].interval, "SECOND");
+        assert_eq!(counts[0].interval_num, 10);
+        assert_eq!(counts[1].limit, 20000);
+        assert_eq!(counts[1].count, 0);
+    }
+
+    #[test]
+    fn get_prevented_matches() {
+        let mock_get_prevented_matches = mock("GET", "/api/v3/myPreventedMatches")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "fromPreventedMatchId=1&limit=10&orderId=5&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/get_prevented_matches.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let prevented_matches = account.get_prevented_matches("BTCUSDT", 5, 1, 10).unwrap();
+
+        mock_get_prevented_matches.assert();
+
+        assert_eq!(prevented_matches.len(), 1);
+        let prevented_match = &prevented_matches[0];
+        assert_eq!(prevented_match.prevented_match_id, 1);
+        assert_eq!(prevented_match.taker_order_id, 5);
+        assert_eq!(prevented_match.maker_order_id, 3);
+        assert_eq!(prevented_match.self_trade_prevention_mode, "EXPIRE_MAKER");
+        assert!(approx_eq!(
+            f64,
+            prevented_match.maker_prevented_quantity,
+            1.3,
+            ulps = 2
+        ));
+    }
+
+    #[test]
+    fn get_allocations() {
+        let mock_get_allocations = mock("GET", "/api/v3/myAllocations")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "orderId=1&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/get_allocations.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let allocations = account
+            .get_allocations("BTCUSDT", None, None, None, None, 1)
+            .unwrap();
+
+        mock_get_allocations.assert();
+
+        assert_eq!(allocations.len(), 1);
+        let allocation = &allocations[0];
+        assert_eq!(allocation.allocation_type, "SOR");
+        assert_eq!(allocation.order_list_id, -1);
+        assert!(approx_eq!(f64, allocation.qty, 5.0, ulps = 2));
+        assert!(allocation.is_buyer);
+        assert!(!allocation.is_allocator);
+    }
+
+    #[test]
+    fn sor_order() {
+        let mock_sor_order = mock("POST", "/api/v3/sor/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "price=31000&quantity=0.5&recvWindow=1234&side=BUY&symbol=BTCUSDT&timeInForce=GTC&timestamp=\\d+&type=LIMIT".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/sor_order.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order = SorOrderRequest {
+            symbol: "BTCUSDT".into(),
+            qty: 0.5,
+            price: Some(31000.0),
+            order_side: OrderSide::Buy,
+            order_type: OrderType::Limit,
+            time_in_force: Some(TimeInForce::GTC),
+            new_client_order_id: None,
+        };
+        let transaction: Transaction = account.sor_order(order).unwrap();
+
+        mock_sor_order.assert();
+
+        assert_eq!(transaction.order_id, 2);
+        assert_eq!(transaction.status, "FILLED");
+        assert_eq!(transaction.working_floor, Some("SOR".into()));
+        assert_eq!(transaction.used_sor, Some(true));
+
+        let fills = transaction.fills.unwrap();
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].trade_id, None);
+        assert_eq!(fills[0].alloc_id, Some(0));
+        assert_eq!(fills[0].match_type, Some("ONE_PARTY_TRADE_REPORT".into()));
+        assert!(approx_eq!(f64, fills[0].price, 28000.0, ulps = 2));
+    }
+
+    #[test]
+    fn test_sor_order() {
+        let mock_test_sor_order = mock("POST", "/api/v3/sor/order/test")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "quantity=0.5&recvWindow=1234&side=SELL&symbol=BTCUSDT&timestamp=\\d+&type=MARKET"
+                    .into(),
+            ))
+            .with_body("{}")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order = SorOrderRequest {
+            symbol: "BTCUSDT".into(),
+            qty: 0.5,
+            price: None,
+            order_side: OrderSide::Sell,
+            order_type: OrderType::Market,
+            time_in_force: None,
+            new_client_order_id: None,
+        };
+        account.test_sor_order(order).unwrap();
+
+        mock_test_sor_order.assert();
+    }
 }
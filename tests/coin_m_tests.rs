@@ -0,0 +1,166 @@
+use binance::api::*;
+use binance::config::*;
+use binance::futures::coin_m::account::*;
+use binance::futures::coin_m::market::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+    use float_cmp::*;
+    use binance::account::{OrderSide, TimeInForce};
+
+    #[test]
+    fn exchange_info() {
+        let mock_exchange_info = mock("GET", "/dapi/v1/exchangeInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/futures/coin_m/exchange_info.json")
+            .create();
+
+        let config = Config::default().set_coin_m_rest_api_endpoint(mockito::server_url());
+        let market: CoinMMarket = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let info = market.exchange_info().unwrap();
+
+        mock_exchange_info.assert();
+
+        assert_eq!(info.symbols.len(), 2);
+        let perpetual = &info.symbols[0];
+        assert!(perpetual.is_perpetual());
+        assert_eq!(perpetual.contract_size, 100);
+        assert_eq!(perpetual.margin_asset, "BTC");
+        assert!(approx_eq!(
+            f64,
+            perpetual.contracts_to_base(5, 50000.0),
+            0.01,
+            ulps = 2
+        ));
+        assert_eq!(perpetual.base_to_contracts(0.0115, 50000.0), 5);
+
+        let delivery = &info.symbols[1];
+        assert!(!delivery.is_perpetual());
+        assert_eq!(delivery.contract_type, "CURRENT_QUARTER");
+        assert_eq!(delivery.delivery_date, 1727424000000);
+        assert_eq!(delivery.contract_size, 10);
+    }
+
+    #[test]
+    fn get_mark_prices() {
+        let mock_mark_prices = mock("GET", "/dapi/v1/premiumIndex")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("pair=BTCUSD".into()))
+            .with_body_from_file("tests/mocks/futures/coin_m/get_mark_prices.json")
+            .create();
+
+        let config = Config::default().set_coin_m_rest_api_endpoint(mockito::server_url());
+        let market: CoinMMarket = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let mark_prices = market.get_mark_prices(None, Some("BTCUSD".into())).unwrap();
+
+        mock_mark_prices.assert();
+
+        assert_eq!(mark_prices.len(), 2);
+        assert_eq!(mark_prices[0].last_funding_rate, Some(0.00071003));
+        assert!(approx_eq!(
+            f64,
+            mark_prices[0].mark_price,
+            11029.69574559,
+            ulps = 2
+        ));
+        assert_eq!(mark_prices[1].symbol, "BTCUSD_200925");
+        assert_eq!(mark_prices[1].last_funding_rate, None);
+        assert_eq!(mark_prices[1].interest_rate, None);
+    }
+
+    #[test]
+    fn new_order() {
+        let mock_new_order = mock("POST", "/dapi/v1/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "newClientOrderId=testOrder&price=30000&quantity=10&recvWindow=1234&side=BUY&symbol=BTCUSD_PERP&timeInForce=GTC&timestamp=\\d+&type=LIMIT&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/coin_m/new_order.json")
+            .create();
+
+        let config = Config::default()
+            .set_coin_m_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: CoinMAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order = CoinMOrderRequest {
+            new_client_order_id: Some("testOrder".into()),
+            ..CoinMOrderRequest::limit("BTCUSD_PERP", OrderSide::Buy, 10, 30000.0, TimeInForce::GTC)
+        };
+        let order = account.new_order(order).unwrap();
+
+        mock_new_order.assert();
+
+        assert_eq!(order.order_id, 22542179);
+        assert_eq!(order.pair, "BTCUSD");
+        assert!(approx_eq!(f64, order.orig_qty, 10.0, ulps = 2));
+        assert!(approx_eq!(f64, order.cum_base, 0.0, ulps = 2));
+        assert_eq!(order.order_type, "LIMIT");
+    }
+
+    #[test]
+    fn account_information() {
+        let mock_account_information = mock("GET", "/dapi/v1/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/coin_m/account_information.json")
+            .create();
+
+        let config = Config::default()
+            .set_coin_m_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: CoinMAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let information = account.account_information().unwrap();
+
+        mock_account_information.assert();
+
+        assert_eq!(information.assets[0].asset, "BTC");
+        assert!(approx_eq!(
+            f64,
+            information.assets[0].margin_balance,
+            0.00241969,
+            ulps = 2
+        ));
+        assert_eq!(information.positions[0].symbol, "BTCUSD_201225");
+        assert!(approx_eq!(
+            f64,
+            information.positions[0].position_amt,
+            3.0,
+            ulps = 2
+        ));
+    }
+
+    #[test]
+    fn position_information() {
+        let mock_position_information = mock("GET", "/dapi/v1/positionRisk")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "marginAsset=BTC&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/coin_m/position_information.json")
+            .create();
+
+        let config = Config::default()
+            .set_coin_m_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: CoinMAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let positions = account
+            .position_information(Some("BTC".into()), None)
+            .unwrap();
+
+        mock_position_information.assert();
+
+        assert_eq!(positions.len(), 1);
+        assert_eq!(positions[0].margin_type, "cross");
+        assert!(!positions[0].is_auto_add_margin);
+        assert!(approx_eq!(f64, positions[0].leverage, 125.0, ulps = 2));
+    }
+}
@@ -0,0 +1,243 @@
+use binance::api::*;
+use binance::config::*;
+use binance::convert::*;
+use binance::market::*;
+use binance::model::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+    use float_cmp::*;
+
+    #[test]
+    fn get_quote() {
+        let mock_get_quote = mock("POST", "/sapi/v1/convert/getQuote")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "fromAmount=0.1&fromAsset=BTC&recvWindow=1234&timestamp=\\d+&toAsset=USDT&validTime=30s&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/convert/get_quote.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let convert: Convert = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let quote = convert
+            .get_quote(
+                "BTC",
+                "USDT",
+                ConvertAmount::From(0.1),
+                None,
+                Some(QuoteValidTime::ThirtySeconds),
+            )
+            .unwrap();
+
+        mock_get_quote.assert();
+
+        assert_eq!(quote.quote_id, "12415572564");
+        assert!(approx_eq!(f64, quote.ratio, 38163.7, ulps = 2));
+        assert!(approx_eq!(f64, quote.to_amount, 3816.37, ulps = 2));
+        assert_eq!(quote.valid_timestamp, 1623319461670);
+    }
+
+    #[test]
+    fn accept_quote() {
+        let mock_accept_quote = mock("POST", "/sapi/v1/convert/acceptQuote")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "quoteId=12415572564&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/convert/accept_quote.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let convert: Convert = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let acceptance = convert.accept_quote("12415572564").unwrap();
+
+        mock_accept_quote.assert();
+
+        assert_eq!(acceptance.order_id, 933256278426274426);
+        assert_eq!(acceptance.order_status, ConvertOrderStatus::Process);
+    }
+
+    #[test]
+    fn get_order_status() {
+        let mock_order_status = mock("GET", "/sapi/v1/convert/orderStatus")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "orderId=933256278426274426&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/convert/get_order_status.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let convert: Convert = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order = convert.get_order_status(933256278426274426).unwrap();
+
+        mock_order_status.assert();
+
+        assert_eq!(order.order_id, 933256278426274426);
+        assert_eq!(order.order_status, ConvertOrderStatus::Success);
+        assert_eq!(order.from_asset, "BTC");
+        assert!(approx_eq!(f64, order.to_amount, 20.0, ulps = 2));
+        assert!(order.quote_id.is_none());
+    }
+
+    #[test]
+    fn get_trade_flow() {
+        let mock_trade_flow = mock("GET", "/sapi/v1/convert/tradeFlow")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "endTime=1626416139000&limit=100&recvWindow=1234&startTime=1623824139000&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/convert/get_trade_flow.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let convert: Convert = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let trade_flow = convert
+            .get_trade_flow(1623824139000, 1626416139000, 100)
+            .unwrap();
+
+        mock_trade_flow.assert();
+
+        assert!(!trade_flow.more_data);
+        assert_eq!(trade_flow.list.len(), 1);
+        let trade = &trade_flow.list[0];
+        assert_eq!(
+            trade.quote_id.as_deref(),
+            Some("f3b91c525b2644c7bc1e1cd31b6e1aa6")
+        );
+        assert!(approx_eq!(f64, trade.inverse_ratio, 324.99, ulps = 2));
+    }
+
+    #[test]
+    fn convert_within_tolerance() {
+        let mock_get_price = mock("GET", "/api/v3/ticker/price")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("symbol=BTCUSDT".into()))
+            .with_body_from_file("tests/mocks/convert/get_price_btcusdt.json")
+            .create();
+        let mock_get_quote = mock("POST", "/sapi/v1/convert/getQuote")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "fromAmount=0.1&fromAsset=BTC&recvWindow=1234&timestamp=\\d+&toAsset=USDT&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/convert/get_quote.json")
+            .create();
+        let mock_accept_quote = mock("POST", "/sapi/v1/convert/acceptQuote")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "quoteId=12415572564&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/convert/accept_quote.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let convert: Convert = Binance::new_with_config(None, None, &config);
+        let market: Market = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let acceptance = convert
+            .convert_within_tolerance(&market, "BTC", "USDT", ConvertAmount::From(0.1), 0.005)
+            .unwrap();
+
+        mock_get_price.assert();
+        mock_get_quote.assert();
+        mock_accept_quote.assert();
+
+        assert_eq!(acceptance.order_id, 933256278426274426);
+    }
+
+    #[test]
+    fn convert_within_tolerance_rejected() {
+        let mock_get_price = mock("GET", "/api/v3/ticker/price")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("symbol=ETHBTC".into()))
+            .with_body_from_file("tests/mocks/convert/get_price_ethbtc.json")
+            .create();
+        let mock_get_price_invalid = mock("GET", "/api/v3/ticker/price")
+            .with_status(400)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("symbol=BTCETH".into()))
+            .with_body_from_file("tests/mocks/convert/invalid_symbol.json")
+            .create();
+        let mock_get_quote = mock("POST", "/sapi/v1/convert/getQuote")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "fromAmount=0.1&fromAsset=BTC&recvWindow=1234&timestamp=\\d+&toAsset=ETH&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/convert/get_quote_btceth.json")
+            .create();
+        let mock_accept_quote = mock("POST", "/sapi/v1/convert/acceptQuote")
+            .match_query(Matcher::Any)
+            .expect(0)
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let convert: Convert = Binance::new_with_config(None, None, &config);
+        let market: Market = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        // BTCETH is not listed, the market ratio is 1 / 0.05 from ETHBTC
+        let result =
+            convert.convert_within_tolerance(&market, "BTC", "ETH", ConvertAmount::From(0.1), 0.01);
+
+        mock_get_price_invalid.assert();
+        mock_get_price.assert();
+        mock_get_quote.assert();
+        mock_accept_quote.assert();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn convert_within_tolerance_price_error() {
+        let mock_get_price = mock("GET", "/api/v3/ticker/price")
+            .with_status(503)
+            .match_query(Matcher::Regex("symbol=BTCBUSD".into()))
+            .create();
+        let mock_get_price_inverted = mock("GET", "/api/v3/ticker/price")
+            .match_query(Matcher::Regex("symbol=BUSDBTC".into()))
+            .expect(0)
+            .create();
+        let mock_get_quote = mock("POST", "/sapi/v1/convert/getQuote")
+            .match_query(Matcher::Any)
+            .expect(0)
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let convert: Convert = Binance::new_with_config(None, None, &config);
+        let market: Market = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let result = convert.convert_within_tolerance(
+            &market,
+            "BTC",
+            "BUSD",
+            ConvertAmount::From(0.1),
+            0.01,
+        );
+
+        mock_get_price.assert();
+        mock_get_price_inverted.assert();
+        mock_get_quote.assert();
+
+        assert_eq!(result.unwrap_err().to_string(), "Service Unavailable");
+    }
+}
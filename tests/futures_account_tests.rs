@@ -1,14 +1,17 @@
 use binance::api::*;
 use binance::config::*;
 use binance::futures::account::*;
+use binance::futures::heartbeat::CountdownHeartbeat;
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use mockito::{mock, Matcher};
+    use std::thread;
+    use std::time::Duration;
     use float_cmp::*;
-    use binance::account::OrderSide;
-    use binance::futures::model::Transaction;
+    use binance::account::{OrderSide, TimeInForce};
+    use binance::futures::model::{IncomeType, Transaction};
 
     #[test]
     fn change_initial_leverage() {
@@ -166,4 +169,890 @@ mod tests {
         assert_eq!(transaction.close_position, true);
         assert!(approx_eq!(f64, transaction.stop_price, 7.4, ulps = 2));
     }
+
+    #[test]
+    fn get_order() {
+        let mock_get_order = mock("GET", "/fapi/v1/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "orderId=1917641&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/get_order.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order = account.get_order("BTCUSDT", 1917641).unwrap();
+
+        mock_get_order.assert();
+
+        assert_eq!(order.order_id, 1917641);
+        assert_eq!(order.status, "NEW");
+        assert_eq!(order.time, Some(1579276756075));
+        assert!(approx_eq!(f64, order.stop_price, 9300.0, ulps = 2));
+    }
+
+    #[test]
+    fn get_all_orders() {
+        let mock_all_orders = mock("GET", "/fapi/v1/allOrders")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "limit=2&orderId=1917641&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/get_all_orders.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let orders = account
+            .get_all_orders("BTCUSDT", 1917641, None, None, 2)
+            .unwrap();
+
+        mock_all_orders.assert();
+
+        assert_eq!(orders.len(), 2);
+        assert_eq!(orders[0].status, "FILLED");
+        assert!(approx_eq!(f64, orders[0].avg_price, 9310.5, ulps = 2));
+        assert_eq!(orders[1].client_order_id, "second");
+        assert!(orders[1].reduce_only);
+    }
+
+    #[test]
+    fn get_force_orders() {
+        let mock_force_orders = mock("GET", "/fapi/v1/forceOrders")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "autoCloseType=LIQUIDATION&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/get_force_orders.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let orders = account
+            .get_force_orders(
+                Some("BTCUSDT".into()),
+                Some(AutoCloseType::Liquidation),
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        mock_force_orders.assert();
+
+        assert_eq!(orders[0].order_id, 6071832819);
+        assert_eq!(orders[0].time_in_force, "IOC");
+        assert!(!orders[0].price_protect);
+    }
+
+    #[test]
+    fn user_trades_range() {
+        let mock_first_window = mock("GET", "/fapi/v1/userTrades")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "endTime=1700604799999&limit=1000&recvWindow=1234&startTime=1700000000000&symbol=BTCUSDT&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/user_trades_first_window.json")
+            .create();
+        let mock_second_window = mock("GET", "/fapi/v1/userTrades")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "endTime=1700864000000&limit=1000&recvWindow=1234&startTime=1700604800000&symbol=BTCUSDT&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/user_trades_second_window.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let trades = account
+            .user_trades_range("BTCUSDT", 1700000000000, 1700864000000)
+            .collect::<binance::errors::Result<Vec<_>>>()
+            .unwrap();
+
+        mock_first_window.assert();
+        mock_second_window.assert();
+
+        assert_eq!(trades.len(), 2);
+        assert_eq!(trades[0].id, 698759);
+        assert!(approx_eq!(
+            f64,
+            trades[0].realized_pnl,
+            -0.91539999,
+            ulps = 2
+        ));
+        assert_eq!(trades[1].order_id, 25851990);
+        assert!(trades[1].maker);
+    }
+
+    fn user_trades_body(trades: &[(u64, u64)]) -> String {
+        let trades: Vec<String> = trades
+            .iter()
+            .map(|(id, time)| {
+                format!(
+                    r#"{{"buyer":false,"commission":"0","commissionAsset":"USDT","id":{},"maker":false,"orderId":1,"price":"7819.01","qty":"0.002","quoteQty":"15.63802","realizedPnl":"0","side":"SELL","positionSide":"SHORT","symbol":"BTCUSDT","time":{}}}"#,
+                    id, time
+                )
+            })
+            .collect();
+        format!("[{}]", trades.join(","))
+    }
+
+    #[test]
+    fn user_trades_range_full_page_in_one_millisecond() {
+        let full_page: Vec<(u64, u64)> = (1..=1000).map(|id| (id, 1700000000000)).collect();
+        let mock_full_page = mock("GET", "/fapi/v1/userTrades")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "endTime=1700000000999&limit=1000&recvWindow=1234&startTime=1700000000000&symbol=BTCUSDT&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body(user_trades_body(&full_page))
+            .create();
+        let mock_by_id = mock("GET", "/fapi/v1/userTrades")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "fromId=1001&limit=1000&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body(user_trades_body(&[
+                (1001, 1700000000000),
+                (1002, 1700000000500),
+            ]))
+            .create();
+        let mock_after = mock("GET", "/fapi/v1/userTrades")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "endTime=1700000000999&limit=1000&recvWindow=1234&startTime=1700000000001&symbol=BTCUSDT&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body(user_trades_body(&[(1002, 1700000000500)]))
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let trades = account
+            .user_trades_range("BTCUSDT", 1700000000000, 1700000000999)
+            .collect::<binance::errors::Result<Vec<_>>>()
+            .unwrap();
+
+        mock_full_page.assert();
+        mock_by_id.assert();
+        mock_after.assert();
+
+        assert_eq!(trades.len(), 1002);
+        assert_eq!(trades[1000].id, 1001);
+        assert_eq!(trades[1001].id, 1002);
+    }
+
+    fn limit_order_request(price: f64) -> CustomOrderRequest {
+        CustomOrderRequest {
+            symbol: "BTCUSDT".into(),
+            side: OrderSide::Buy,
+            position_side: None,
+            order_type: OrderType::Limit,
+            time_in_force: Some(TimeInForce::GTC),
+            qty: Some(0.01),
+            reduce_only: None,
+            price: Some(price),
+            stop_price: None,
+            close_position: None,
+            activation_price: None,
+            callback_rate: None,
+            working_type: None,
+            price_protect: None,
+        }
+    }
+
+    #[test]
+    fn custom_batch_orders() {
+        let mock_batch_orders = mock("POST", "/fapi/v1/batchOrders")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "batchOrders=%5B%7B%22price%22%3A%2230000%22%2C%22quantity%22%3A%220.01%22%2C%22side%22%3A%22BUY%22%2C%22symbol%22%3A%22BTCUSDT%22%2C%22timeInForce%22%3A%22GTC%22%2C%22type%22%3A%22LIMIT%22%7D%2C%7B.*%7D%5D&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/custom_batch_orders.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let results = account
+            .custom_batch_orders(vec![
+                limit_order_request(30000.0),
+                limit_order_request(29000.0),
+            ])
+            .unwrap();
+
+        mock_batch_orders.assert();
+
+        assert_eq!(results.len(), 2);
+        match &results[0] {
+            Ok(transaction) => assert_eq!(transaction.order_id, 22542179),
+            Err(_) => panic!("First order should be placed"),
+        }
+        match &results[1] {
+            Err(error) => assert_eq!(error.code, -2019),
+            Ok(_) => panic!("Second order should be rejected"),
+        }
+    }
+
+    #[test]
+    fn custom_batch_orders_too_many() {
+        let config = Config::default().set_futures_rest_api_endpoint(mockito::server_url());
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let orders = (0..FUTURES_BATCH_ORDERS_LIMIT + 1)
+            .map(|_| limit_order_request(30000.0))
+            .collect();
+
+        assert!(account.custom_batch_orders(orders).is_err());
+    }
+
+    #[test]
+    fn cancel_batch_orders() {
+        let mock_cancel_batch_orders = mock("DELETE", "/fapi/v1/batchOrders")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "orderIdList=%5B22542179%2C22542180%5D&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/cancel_batch_orders.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let results = account
+            .cancel_batch_orders("BTCUSDT", vec![22542179, 22542180], vec![])
+            .unwrap();
+
+        mock_cancel_batch_orders.assert();
+
+        assert_eq!(results[0].as_ref().unwrap().status, "CANCELED");
+        assert_eq!(results[1].as_ref().unwrap_err().msg, "Unknown order sent.");
+    }
+
+    #[test]
+    fn cancel_batch_orders_with_both_id_lists() {
+        let config = Config::default().set_futures_rest_api_endpoint(mockito::server_url());
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+
+        assert!(account
+            .cancel_batch_orders("BTCUSDT", vec![22542179], vec!["ladder1".into()])
+            .is_err());
+    }
+
+    #[test]
+    fn modify_order() {
+        let mock_modify_order = mock("PUT", "/fapi/v1/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "orderId=20072994037&price=30005&quantity=1&recvWindow=1234&side=BUY&symbol=BTCUSDT&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/modify_order.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let transaction = account
+            .modify_order(ModifyOrderRequest::new(
+                "BTCUSDT",
+                20072994037,
+                OrderSide::Buy,
+                1.0,
+                30005.0,
+            ))
+            .unwrap();
+
+        mock_modify_order.assert();
+
+        assert_eq!(transaction.order_id, 20072994037);
+        assert_eq!(transaction.price_match.as_deref(), Some("NONE"));
+    }
+
+    #[test]
+    fn modify_order_with_price_match() {
+        let mock_modify_order = mock("PUT", "/fapi/v1/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "origClientOrderId=LJ9R4QZDihCaS8UAOOLpgW&priceMatch=QUEUE_5&quantity=1&recvWindow=1234&side=BUY&symbol=BTCUSDT&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/modify_order.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let modify_request = ModifyOrderRequest {
+            order_id: None,
+            orig_client_order_id: Some("LJ9R4QZDihCaS8UAOOLpgW".into()),
+            ..ModifyOrderRequest::with_price_match(
+                "BTCUSDT",
+                0,
+                OrderSide::Buy,
+                1.0,
+                PriceMatch::Queue5,
+            )
+        };
+        account.modify_order(modify_request).unwrap();
+
+        mock_modify_order.assert();
+    }
+
+    #[test]
+    fn modify_order_with_price_and_price_match() {
+        let config = Config::default().set_futures_rest_api_endpoint(mockito::server_url());
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let modify_request = ModifyOrderRequest {
+            price_match: Some(PriceMatch::Opponent),
+            ..ModifyOrderRequest::new("BTCUSDT", 20072994037, OrderSide::Buy, 1.0, 30005.0)
+        };
+
+        assert!(account.modify_order(modify_request).is_err());
+    }
+
+    #[test]
+    fn get_order_amendments() {
+        let mock_order_amendments = mock("GET", "/fapi/v1/orderAmendment")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "orderId=20072994037&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/get_order_amendments.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let amendments = account
+            .get_order_amendments("BTCUSDT", 20072994037, None, None, None)
+            .unwrap();
+
+        mock_order_amendments.assert();
+
+        assert_eq!(amendments[0].amendment_id, 5363);
+        assert_eq!(amendments[0].amendment.count, 3);
+        assert!(approx_eq!(
+            f64,
+            amendments[0].amendment.price.after,
+            30003.2,
+            ulps = 2
+        ));
+    }
+
+    #[test]
+    fn get_position_mode() {
+        let mock_position_mode = mock("GET", "/fapi/v1/positionSide/dual")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/get_position_mode.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let position_mode = account.get_position_mode().unwrap();
+
+        mock_position_mode.assert();
+
+        assert!(position_mode.dual_side_position);
+    }
+
+    #[test]
+    fn change_margin_type() {
+        let mock_margin_type = mock("POST", "/fapi/v1/marginType")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "marginType=ISOLATED&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/change_position_mode.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        account
+            .change_margin_type("BTCUSDT", MarginType::Isolated)
+            .unwrap();
+
+        mock_margin_type.assert();
+    }
+
+    #[test]
+    fn modify_position_margin() {
+        let mock_position_margin = mock("POST", "/fapi/v1/positionMargin")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "amount=100&positionSide=LONG&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&type=1&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/modify_position_margin.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let response = account
+            .modify_position_margin(
+                "BTCUSDT",
+                100.0,
+                PositionMarginAction::Add,
+                Some(PositionSide::Long),
+            )
+            .unwrap();
+
+        mock_position_margin.assert();
+
+        assert_eq!(response.code, 200);
+        assert_eq!(response.adjustment_type, 1);
+        assert!(approx_eq!(f64, response.amount, 100.0, ulps = 2));
+    }
+
+    #[test]
+    fn get_position_margin_history() {
+        let mock_history = mock("GET", "/fapi/v1/positionMargin/history")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "limit=2&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&type=1&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/get_position_margin_history.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let history = account
+            .get_position_margin_history("BTCUSDT", Some(PositionMarginAction::Add), None, None, 2)
+            .unwrap();
+
+        mock_history.assert();
+
+        assert_eq!(history.len(), 2);
+        assert!(approx_eq!(f64, history[0].amount, 23.36332311, ulps = 2));
+        assert_eq!(history[1].position_side, "LONG");
+        assert_eq!(history[1].delta_type.as_deref(), Some("USER_ADJUST"));
+    }
+
+    #[test]
+    fn multi_assets_margin() {
+        let mock_get = mock("GET", "/fapi/v1/multiAssetsMargin")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/get_multi_assets_margin.json")
+            .create();
+        let mock_change = mock("POST", "/fapi/v1/multiAssetsMargin")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "multiAssetsMargin=true&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/change_position_mode.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let multi_assets_margin = account.get_multi_assets_margin().unwrap();
+        account.change_multi_assets_margin(true).unwrap();
+
+        mock_get.assert();
+        mock_change.assert();
+
+        assert!(!multi_assets_margin.multi_assets_margin);
+    }
+
+    #[test]
+    fn income_range() {
+        let mock_first_page = mock("GET", "/fapi/v1/income")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "endTime=1700086400000&limit=1000&page=1&recvWindow=1234&startTime=1700000000000&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/income_page_1.json")
+            .create();
+        let mock_second_page = mock("GET", "/fapi/v1/income")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "endTime=1700086400000&limit=1000&page=2&recvWindow=1234&startTime=1700000000000&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/income_page_2.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let incomes = account
+            .income_range(None, None, 1700000000000, 1700086400000)
+            .collect::<binance::errors::Result<Vec<_>>>()
+            .unwrap();
+
+        mock_first_page.assert();
+        mock_second_page.assert();
+
+        assert_eq!(incomes.len(), 1002);
+        assert_eq!(incomes[0].income_type, IncomeType::FundingFee);
+        assert!(approx_eq!(f64, incomes[0].income, -0.01, ulps = 2));
+        assert_eq!(incomes[1001].income_type, IncomeType::Unknown);
+        assert_eq!(incomes[1001].tran_id, 9689323393);
+    }
+
+    #[test]
+    fn get_income_with_type() {
+        let mock_income = mock("GET", "/fapi/v1/income")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "incomeType=REALIZED_PNL&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body("[]")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let incomes = account
+            .get_income(
+                Some("BTCUSDT".into()),
+                Some(IncomeType::RealizedPnl),
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        mock_income.assert();
+
+        assert!(incomes.is_empty());
+    }
+
+    #[test]
+    fn get_income_with_unknown_type() {
+        let mock_income = mock("GET", "/fapi/v1/income")
+            .match_query(Matcher::Any)
+            .expect(0)
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let result = account.get_income(None, Some(IncomeType::Unknown), None, None, None, None);
+
+        mock_income.assert();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn get_leverage_brackets() {
+        let mock_leverage_bracket = mock("GET", "/fapi/v1/leverageBracket")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&symbol=ETHUSDT&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/get_leverage_bracket.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let brackets = account
+            .get_leverage_brackets(Some("ETHUSDT".into()))
+            .unwrap();
+
+        mock_leverage_bracket.assert();
+
+        assert_eq!(brackets.len(), 1);
+        assert_eq!(brackets[0].bracket(5000.0).unwrap().initial_leverage, 75);
+        assert_eq!(brackets[0].bracket(10000.0).unwrap().initial_leverage, 50);
+        assert!(brackets[0].bracket(50000.0).is_none());
+    }
+
+    #[test]
+    fn get_adl_quantiles() {
+        let mock_adl_quantiles = mock("GET", "/fapi/v1/adlQuantile")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/get_adl_quantiles.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let quantiles = account.get_adl_quantiles(None).unwrap();
+
+        mock_adl_quantiles.assert();
+
+        assert_eq!(quantiles[0].adl_quantile.hedge, Some(3));
+        assert_eq!(quantiles[0].adl_quantile.both, None);
+        assert_eq!(quantiles[1].adl_quantile.short, Some(2));
+        assert_eq!(quantiles[1].adl_quantile.both, Some(0));
+    }
+
+    #[test]
+    fn get_commission_rate() {
+        let mock_commission_rate = mock("GET", "/fapi/v1/commissionRate")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/get_commission_rate.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let commission_rate = account.get_commission_rate("BTCUSDT").unwrap();
+
+        mock_commission_rate.assert();
+
+        assert!(approx_eq!(
+            f64,
+            commission_rate.taker_commission_rate,
+            0.0004,
+            ulps = 2
+        ));
+    }
+
+    #[test]
+    fn get_income_download_link() {
+        let mock_download_link = mock("GET", "/fapi/v1/income/asyn/id")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "downloadId=545923594199212032&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/get_income_download_link.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let download_link = account
+            .get_income_download_link("545923594199212032")
+            .unwrap();
+
+        mock_download_link.assert();
+
+        assert_eq!(download_link.status, "completed");
+        assert_eq!(download_link.url, "www.binance.com");
+        assert_eq!(download_link.is_expired, None);
+    }
+
+    #[test]
+    fn countdown_cancel_all() {
+        let mock_countdown = mock("POST", "/fapi/v1/countdownCancelAll")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "countdownTime=100000&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/countdown_cancel_all.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let countdown = account.countdown_cancel_all("BTCUSDT", 100000).unwrap();
+
+        mock_countdown.assert();
+
+        assert_eq!(countdown.symbol, "BTCUSDT");
+        assert_eq!(countdown.countdown_time, 100000);
+    }
+
+    #[test]
+    fn countdown_heartbeat_refreshes_while_alive() {
+        let mock_countdown = mock("POST", "/fapi/v1/countdownCancelAll")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "countdownTime=1000&recvWindow=1234&symbol=ETHUSDT&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/countdown_cancel_all.json")
+            .expect_at_least(2)
+            .create();
+        let mock_disable = mock("POST", "/fapi/v1/countdownCancelAll")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "countdownTime=0&recvWindow=1234&symbol=ETHUSDT&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/countdown_cancel_all.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let heartbeat = CountdownHeartbeat::start(
+            account,
+            vec!["ETHUSDT".into()],
+            Duration::from_secs(1),
+            Duration::from_millis(20),
+        )
+        .unwrap();
+        for _ in 0..10 {
+            heartbeat.alive();
+            thread::sleep(Duration::from_millis(20));
+        }
+        assert_eq!(heartbeat.last_error(), None);
+        heartbeat.stop().unwrap();
+
+        mock_countdown.assert();
+        mock_disable.assert();
+    }
+
+    #[test]
+    fn countdown_heartbeat_stops_refreshing_when_not_alive() {
+        let mock_countdown = mock("POST", "/fapi/v1/countdownCancelAll")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "countdownTime=2000&recvWindow=1234&symbol=LTCUSDT&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/countdown_cancel_all.json")
+            .expect(1)
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let heartbeat = CountdownHeartbeat::start(
+            account,
+            vec!["LTCUSDT".into()],
+            Duration::from_secs(2),
+            Duration::from_millis(20),
+        )
+        .unwrap();
+        thread::sleep(Duration::from_millis(100));
+        drop(heartbeat);
+
+        mock_countdown.assert();
+    }
+
+    #[test]
+    fn countdown_heartbeat_clears_error_after_recovery() {
+        let countdown_query =
+            "countdownTime=3000&recvWindow=1234&symbol=XRPUSDT&timestamp=\\d+&signature=.*";
+        let mock_countdown = mock("POST", "/fapi/v1/countdownCancelAll")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(countdown_query.into()))
+            .with_body_from_file("tests/mocks/futures/account/countdown_cancel_all.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let heartbeat = CountdownHeartbeat::start(
+            account,
+            vec!["XRPUSDT".into()],
+            Duration::from_secs(3),
+            Duration::from_millis(20),
+        )
+        .unwrap();
+        drop(mock_countdown);
+
+        let mock_unavailable = mock("POST", "/fapi/v1/countdownCancelAll")
+            .with_status(503)
+            .match_query(Matcher::Regex(countdown_query.into()))
+            .create();
+        for _ in 0..100 {
+            heartbeat.alive();
+            thread::sleep(Duration::from_millis(20));
+            if heartbeat.last_error().is_some() {
+                break;
+            }
+        }
+        assert!(heartbeat.last_error().is_some());
+        drop(mock_unavailable);
+
+        let _mock_countdown = mock("POST", "/fapi/v1/countdownCancelAll")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(countdown_query.into()))
+            .with_body_from_file("tests/mocks/futures/account/countdown_cancel_all.json")
+            .create();
+        for _ in 0..100 {
+            heartbeat.alive();
+            thread::sleep(Duration::from_millis(20));
+            if heartbeat.last_error().is_none() {
+                break;
+            }
+        }
+        assert_eq!(heartbeat.last_error(), None);
+    }
+
+    #[test]
+    fn countdown_heartbeat_interval_too_long() {
+        let config = Config::default().set_futures_rest_api_endpoint(mockito::server_url());
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+
+        assert!(CountdownHeartbeat::start(
+            account,
+            vec!["BTCUSDT".into()],
+            Duration::from_secs(1),
+            Duration::from_secs(1),
+        )
+        .is_err());
+    }
 }
@@ -1,12 +1,16 @@
 use binance::api::*;
 use binance::config::*;
 use binance::futures::market::FuturesMarket;
-use binance::futures::model::OpenInterestHist;
+use binance::futures::model::{MarkPrices, OpenInterestHist};
+use binance::futures::market::FUNDING_RATE_PAGE_LIMIT;
+use binance::futures::account::ContractType;
+use binance::model::KlineSummaries;
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use mockito::{mock, Matcher};
+    use float_cmp::*;
 
     #[test]
     fn open_interest_statistics() {
@@ -41,4 +45,273 @@ mod tests {
 
         assert_eq!(open_interest_hists, expectation)
     }
+
+    #[test]
+    fn funding_rate_history_range() {
+        let mock_first_page = mock("GET", "/fapi/v1/fundingRate")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "endTime=1700000000000&limit=1000&startTime=1577836800000&symbol=BTCUSDT".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/market/funding_rate_history_page_1.json")
+            .create();
+        let mock_second_page = mock("GET", "/fapi/v1/fundingRate")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "endTime=1700000000000&limit=1000&startTime=1606608000001&symbol=BTCUSDT".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/market/funding_rate_history_page_2.json")
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(mockito::server_url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let rates = market
+            .funding_rate_history_range("BTCUSDT", 1577836800000, 1700000000000)
+            .collect::<binance::errors::Result<Vec<_>>>()
+            .unwrap();
+
+        mock_first_page.assert();
+        mock_second_page.assert();
+
+        assert_eq!(rates.len(), usize::from(FUNDING_RATE_PAGE_LIMIT) + 2);
+        assert_eq!(rates[0].funding_time, 1577836800000);
+        assert_eq!(rates[1001].funding_time, 1606665600000);
+        assert!(approx_eq!(f64, rates[0].funding_rate, 0.0001, ulps = 2));
+        assert_eq!(rates[1001].mark_price, Some(8201.0));
+    }
+
+    #[test]
+    fn get_premium_index() {
+        let mock_premium_index = mock("GET", "/fapi/v1/premiumIndex")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("symbol=BTCUSDT".into()))
+            .with_body_from_file("tests/mocks/futures/market/premium_index.json")
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(mockito::server_url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let premium_index = market.get_premium_index("BTCUSDT").unwrap();
+
+        mock_premium_index.assert();
+
+        assert_eq!(premium_index.symbol, "BTCUSDT");
+        assert!(approx_eq!(
+            f64,
+            premium_index.mark_price,
+            11793.63104562,
+            ulps = 2
+        ));
+        assert!(approx_eq!(
+            f64,
+            premium_index.last_funding_rate,
+            0.00038246,
+            ulps = 2
+        ));
+        assert_eq!(premium_index.next_funding_time, 1597392000000);
+    }
+
+    #[test]
+    fn get_mark_prices() {
+        let mock_mark_prices = mock("GET", "/fapi/v1/premiumIndex")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Missing)
+            .with_body_from_file("tests/mocks/futures/market/mark_prices.json")
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(mockito::server_url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let MarkPrices::AllMarkPrices(mark_prices) = market.get_mark_prices().unwrap();
+
+        mock_mark_prices.assert();
+
+        assert_eq!(mark_prices.len(), 2);
+        assert_eq!(mark_prices[1].symbol, "ETHUSDT");
+        assert!(approx_eq!(
+            f64,
+            mark_prices[1].index_price,
+            389.9850212,
+            ulps = 2
+        ));
+        assert!(approx_eq!(
+            f64,
+            mark_prices[1].interest_rate,
+            0.0001,
+            ulps = 2
+        ));
+    }
+
+    #[test]
+    fn top_long_short_account_ratio() {
+        let mock_ratio = mock("GET", "/futures/data/topLongShortAccountRatio")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("limit=2&period=5m&symbol=BTCUSDT".into()))
+            .with_body_from_file("tests/mocks/futures/market/top_long_short_account_ratio.json")
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(mockito::server_url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let ratios = market
+            .top_long_short_account_ratio("BTCUSDT", "5m", 2, None, None)
+            .unwrap();
+
+        mock_ratio.assert();
+
+        assert_eq!(ratios.len(), 2);
+        assert!(approx_eq!(
+            f64,
+            ratios[0].long_short_ratio,
+            1.8105,
+            ulps = 2
+        ));
+        assert!(approx_eq!(f64, ratios[1].short_account, 0.642, ulps = 2));
+        assert_eq!(ratios[1].timestamp, 1583139900000);
+    }
+
+    #[test]
+    fn taker_long_short_ratio() {
+        let mock_ratio = mock("GET", "/futures/data/takerlongshortRatio")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("period=5m&symbol=BTCUSDT".into()))
+            .with_body_from_file("tests/mocks/futures/market/taker_long_short_ratio.json")
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(mockito::server_url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let ratios = market
+            .taker_long_short_ratio("BTCUSDT", "5m", None, None, None)
+            .unwrap();
+
+        mock_ratio.assert();
+
+        assert!(approx_eq!(f64, ratios[0].buy_sell_ratio, 1.5586, ulps = 2));
+        assert!(approx_eq!(f64, ratios[0].sell_vol, 248.503, ulps = 2));
+        assert_eq!(ratios[0].timestamp, 1585614900000);
+    }
+
+    #[test]
+    fn get_lvt_klines() {
+        let mock_lvt_klines = mock("GET", "/fapi/v1/lvtKlines")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("interval=1h&limit=1&symbol=BTCDOWN".into()))
+            .with_body_from_file("tests/mocks/futures/market/lvt_klines.json")
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(mockito::server_url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let klines = market
+            .get_lvt_klines("BTCDOWN", "1h", 1, None, None)
+            .unwrap();
+
+        mock_lvt_klines.assert();
+
+        assert_eq!(klines.len(), 1);
+        assert_eq!(klines[0].open_time, 1598371200000);
+        assert_eq!(klines[0].close, "5.99403551");
+        assert_eq!(klines[0].leverage, "2.28602984");
+        assert_eq!(klines[0].nav_updates, 6209);
+    }
+
+    #[test]
+    fn get_funding_info() {
+        let mock_funding_info = mock("GET", "/fapi/v1/fundingInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/futures/market/funding_info.json")
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(mockito::server_url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let funding_info = market.get_funding_info().unwrap();
+
+        mock_funding_info.assert();
+
+        assert_eq!(funding_info[0].symbol, "BLZUSDT");
+        assert_eq!(funding_info[0].funding_interval_hours, 8);
+        assert!(approx_eq!(
+            f64,
+            funding_info[0].adjusted_funding_rate_floor,
+            -0.025,
+            ulps = 2
+        ));
+    }
+
+    #[test]
+    fn get_continuous_klines() {
+        let mock_continuous_klines = mock("GET", "/fapi/v1/continuousKlines")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "contractType=CURRENT_QUARTER&interval=1m&limit=1&pair=BTCUSDT".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/market/continuous_klines.json")
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(mockito::server_url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let klines = market
+            .get_continuous_klines("BTCUSDT", ContractType::CurrentQuarter, "1m", 1, None, None)
+            .unwrap();
+
+        mock_continuous_klines.assert();
+
+        match klines {
+            KlineSummaries::AllKlineSummaries(klines) => {
+                assert_eq!(klines.len(), 1);
+                assert_eq!(klines[0].open_time, 1607444700000);
+                assert_eq!(klines[0].close, "18896.13");
+                assert_eq!(klines[0].number_of_trades, 1874);
+            }
+        }
+    }
+
+    #[test]
+    fn get_mark_price_klines() {
+        let mock_mark_price_klines = mock("GET", "/fapi/v1/markPriceKlines")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "endTime=1591256459999&interval=1m&startTime=1591256400000&symbol=BTCUSDT".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/market/mark_price_klines.json")
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(mockito::server_url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let klines = market
+            .get_mark_price_klines("BTCUSDT", "1m", None, 1591256400000, 1591256459999)
+            .unwrap();
+
+        mock_mark_price_klines.assert();
+
+        assert_eq!(klines.len(), 1);
+        assert_eq!(klines[0].open, "9653.69440000");
+        assert_eq!(klines[0].close_time, 1591256459999);
+        assert_eq!(klines[0].basic_count, 60);
+    }
+
+    #[test]
+    fn get_premium_index_klines() {
+        let mock_premium_index_klines = mock("GET", "/fapi/v1/premiumIndexKlines")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("interval=1m&limit=1&symbol=BTCUSDT".into()))
+            .with_body_from_file("tests/mocks/futures/market/mark_price_klines.json")
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(mockito::server_url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let klines = market
+            .get_premium_index_klines("BTCUSDT", "1m", 1, None, None)
+            .unwrap();
+
+        mock_premium_index_klines.assert();
+
+        assert_eq!(klines[0].low, "9651.38600000");
+    }
 }
@@ -0,0 +1,283 @@
+use binance::config::*;
+use binance::futures::websockets::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::net::TcpListener;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::mpsc::{self, Receiver};
+    use std::thread;
+    use tungstenite::handshake::server::{Request, Response};
+    use tungstenite::Message;
+
+    // Accepts one connection, reports the requested path and sends a single event
+    fn serve_once(event: &'static str) -> (String, Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let host = format!("ws://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let callback = |request: &Request, response: Response| {
+                tx.send(request.uri().to_string()).unwrap();
+                Ok(response)
+            };
+            let mut socket = tungstenite::accept_hdr(stream, callback).unwrap();
+            socket.write_message(Message::Text(event.into())).unwrap();
+            while socket.read_message().is_ok() {}
+        });
+        (host, rx)
+    }
+
+    fn receive_one<F>(connect: F) -> FuturesWebsocketEvent
+    where
+        F: FnOnce(&mut FuturesWebSockets) -> binance::errors::Result<()>,
+    {
+        let running = AtomicBool::new(true);
+        let mut received = None;
+        {
+            let mut web_socket = FuturesWebSockets::new(|event: FuturesWebsocketEvent| {
+                received = Some(event);
+                running.store(false, Ordering::Relaxed);
+                Ok(())
+            });
+            connect(&mut web_socket).unwrap();
+            web_socket.event_loop(&running).unwrap();
+            web_socket.disconnect().unwrap();
+        }
+        received.expect("no event was received")
+    }
+
+    const LISTEN_KEY_EXPIRED: &str =
+        r#"{"e":"listenKeyExpired","E":1736996475556,"listenKey":"listen-key"}"#;
+
+    fn handle_mock(file: &str) -> FuturesWebsocketEvent {
+        let msg = fs::read_to_string(format!("tests/mocks/futures/websockets/{}", file)).unwrap();
+        let mut received = None;
+        {
+            let mut web_socket = FuturesWebSockets::new(|event: FuturesWebsocketEvent| {
+                received = Some(event);
+                Ok(())
+            });
+            web_socket.test_handle_msg(&msg).unwrap();
+        }
+        received.expect("no event was handled")
+    }
+
+    #[test]
+    fn account_update() {
+        match handle_mock("account_update.json") {
+            FuturesWebsocketEvent::AccountUpdate(event) => {
+                assert_eq!(event.event_time, 1564745798939);
+                assert_eq!(event.transaction_time, 1564745798938);
+                assert_eq!(event.data.reason, "ORDER");
+                assert_eq!(event.data.balances.len(), 1);
+                assert_eq!(event.data.balances[0].asset, "USDT");
+                assert_eq!(event.data.balances[0].wallet_balance, "122624.12345678");
+                assert_eq!(event.data.balances[0].balance_change, "50.12345678");
+                assert_eq!(event.data.positions.len(), 2);
+                assert_eq!(event.data.positions[1].position_amount, "20");
+                assert_eq!(
+                    event.data.positions[1].breakeven_price.as_deref(),
+                    Some("6563.6")
+                );
+                assert_eq!(event.data.positions[1].position_side, "LONG");
+            }
+            _ => panic!("expected an account update"),
+        }
+    }
+
+    #[test]
+    fn margin_call() {
+        match handle_mock("margin_call.json") {
+            FuturesWebsocketEvent::MarginCall(event) => {
+                assert_eq!(event.cross_wallet_balance.as_deref(), Some("3.16812045"));
+                assert_eq!(event.positions.len(), 1);
+                assert_eq!(event.positions[0].symbol, "ETHUSDT");
+                assert_eq!(event.positions[0].mark_price, "187.17127");
+                assert_eq!(event.positions[0].maintenance_margin_required, "1.614445");
+            }
+            _ => panic!("expected a margin call"),
+        }
+    }
+
+    #[test]
+    fn account_config_update_leverage() {
+        match handle_mock("account_config_update_leverage.json") {
+            FuturesWebsocketEvent::AccountConfigUpdate(event) => {
+                let leverage = event.leverage.unwrap();
+                assert_eq!(leverage.symbol, "BTCUSDT");
+                assert_eq!(leverage.leverage, 25);
+                assert!(event.multi_assets.is_none());
+            }
+            _ => panic!("expected an account config update"),
+        }
+    }
+
+    #[test]
+    fn account_config_update_multi_assets() {
+        match handle_mock("account_config_update_multi_assets.json") {
+            FuturesWebsocketEvent::AccountConfigUpdate(event) => {
+                assert!(event.leverage.is_none());
+                assert!(event.multi_assets.unwrap().multi_assets_margin);
+            }
+            _ => panic!("expected an account config update"),
+        }
+    }
+
+    #[test]
+    fn trade_lite() {
+        match handle_mock("trade_lite.json") {
+            FuturesWebsocketEvent::TradeLite(event) => {
+                assert_eq!(event.symbol, "BTCUSDT");
+                assert_eq!(event.side, "BUY");
+                assert!(!event.is_maker);
+                assert_eq!(event.price_last_filled_trade, "64089.20");
+                assert_eq!(event.qty_last_filled_trade, "0.040");
+                assert_eq!(event.trade_id, 109100866);
+                assert_eq!(event.order_id, 8886774);
+            }
+            _ => panic!("expected a trade lite"),
+        }
+    }
+
+    #[test]
+    fn strategy_update() {
+        match handle_mock("strategy_update.json") {
+            FuturesWebsocketEvent::StrategyUpdate(event) => {
+                assert_eq!(event.strategy.strategy_id, 176054594);
+                assert_eq!(event.strategy.strategy_status, "NEW");
+                assert_eq!(event.strategy.op_code, 8);
+            }
+            _ => panic!("expected a strategy update"),
+        }
+    }
+
+    #[test]
+    fn grid_update() {
+        match handle_mock("grid_update.json") {
+            FuturesWebsocketEvent::GridUpdate(event) => {
+                assert_eq!(event.grid.strategy_id, 176057039);
+                assert_eq!(event.grid.strategy_status, "WORKING");
+                assert_eq!(event.grid.realized_pnl, "-0.00300716");
+                assert_eq!(event.grid.unmatched_qty, "-0.001");
+                assert_eq!(event.grid.update_time, 1669262908197);
+            }
+            _ => panic!("expected a grid update"),
+        }
+    }
+
+    #[test]
+    fn listen_key_expired() {
+        match handle_mock("listen_key_expired.json") {
+            FuturesWebsocketEvent::ListenKeyExpired(event) => {
+                assert_eq!(event.event_time, 1736996475556);
+                assert_eq!(
+                    event.listen_key,
+                    "WsCMN0a4KHUPTQuX6IUnqEZfB1inxmv1qR4kbf1LuEjur5VdbzqvyxqG9TSjVVxv"
+                );
+            }
+            _ => panic!("expected a listen key expired"),
+        }
+    }
+
+    #[test]
+    fn malformed_user_data_event() {
+        let mut web_socket = FuturesWebSockets::new(|_event: FuturesWebsocketEvent| Ok(()));
+        let result = web_socket.test_handle_msg(r#"{"e":"MARGIN_CALL","E":1587727187525}"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn connect_with_config_usd_m() {
+        let (host, path) = serve_once(LISTEN_KEY_EXPIRED);
+        let config = Config::default()
+            .set_ws_endpoint("ws://127.0.0.1:1/ws")
+            .set_futures_ws_endpoint(format!("{}/ws", host));
+
+        let event = receive_one(|web_socket| {
+            web_socket.connect_with_config(FuturesMarket::USDM, "btcusdt@aggTrade", &config)
+        });
+
+        assert_eq!(path.recv().unwrap(), "/ws/btcusdt@aggTrade");
+        assert!(matches!(event, FuturesWebsocketEvent::ListenKeyExpired(_)));
+    }
+
+    #[test]
+    fn connect_with_config_coin_m() {
+        let (host, path) = serve_once(LISTEN_KEY_EXPIRED);
+        let config = Config::default().set_coin_m_ws_endpoint(format!("{}/ws", host));
+
+        receive_one(|web_socket| {
+            web_socket.connect_with_config(FuturesMarket::COINM, "btcusd_perp@markPrice", &config)
+        });
+
+        assert_eq!(path.recv().unwrap(), "/ws/btcusd_perp@markPrice");
+    }
+
+    #[test]
+    fn connect_with_config_options() {
+        let (host, path) = serve_once(LISTEN_KEY_EXPIRED);
+        let config = Config::default().set_options_ws_endpoint(format!("{}/ws", host));
+
+        receive_one(|web_socket| {
+            web_socket.connect_with_config(FuturesMarket::Vanilla, "BTC@index", &config)
+        });
+
+        assert_eq!(path.recv().unwrap(), "/ws/BTC@index");
+    }
+
+    #[test]
+    fn connect_with_config_trailing_slash() {
+        let (host, path) = serve_once(LISTEN_KEY_EXPIRED);
+        let config = Config::default().set_futures_ws_endpoint(format!("{}/ws/", host));
+
+        receive_one(|web_socket| {
+            web_socket.connect_with_config(FuturesMarket::USDM, "btcusdt@aggTrade", &config)
+        });
+
+        assert_eq!(path.recv().unwrap(), "/ws/btcusdt@aggTrade");
+    }
+
+    #[test]
+    fn connect_multiple_streams_with_config() {
+        let (host, path) = serve_once(
+            r#"{"stream":"btcusdt@aggTrade","data":{"e":"listenKeyExpired","E":1736996475556,"listenKey":"listen-key"}}"#,
+        );
+        let config = Config::default().set_futures_ws_endpoint(format!("{}/ws", host));
+        let streams = vec![
+            "btcusdt@aggTrade".to_string(),
+            "ethusdt@markPrice".to_string(),
+        ];
+
+        let event = receive_one(|web_socket| {
+            web_socket.connect_multiple_streams_with_config(FuturesMarket::USDM, &streams, &config)
+        });
+
+        assert_eq!(
+            path.recv().unwrap(),
+            "/stream?streams=btcusdt@aggTrade/ethusdt@markPrice"
+        );
+        assert!(matches!(event, FuturesWebsocketEvent::ListenKeyExpired(_)));
+    }
+
+    #[test]
+    fn testnet_ws_endpoints() {
+        let config = Config::testnet();
+
+        assert_eq!(
+            config.futures_ws_endpoint,
+            "wss://stream.binancefuture.com/ws"
+        );
+        assert_eq!(
+            config.coin_m_ws_endpoint,
+            "wss://dstream.binancefuture.com/ws"
+        );
+        assert_eq!(
+            config.options_ws_endpoint,
+            "wss://testnetws.binanceops.com/ws"
+        );
+        assert!(config.ws_endpoint.starts_with("wss://"));
+    }
+}
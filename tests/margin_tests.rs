@@ -0,0 +1,394 @@
+use binance::api::*;
+use binance::config::*;
+use binance::account::*;
+use binance::margin::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+    use float_cmp::*;
+
+    #[test]
+    fn borrow() {
+        let mock_borrow = mock("POST", "/sapi/v1/margin/borrow-repay")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "amount=1.5&asset=BTC&recvWindow=1234&timestamp=\\d+&type=BORROW&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/borrow.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let transaction = margin.borrow("BTC", 1.5, None).unwrap();
+
+        mock_borrow.assert();
+
+        assert_eq!(transaction.tran_id, 100000001);
+    }
+
+    #[test]
+    fn repay_isolated() {
+        let mock_repay = mock("POST", "/sapi/v1/margin/borrow-repay")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "amount=1.5&asset=BTC&isIsolated=TRUE&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&type=REPAY&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/borrow.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let transaction = margin.repay("BTC", 1.5, Some("BTCUSDT".into())).unwrap();
+
+        mock_repay.assert();
+
+        assert_eq!(transaction.tran_id, 100000001);
+    }
+
+    #[test]
+    fn get_account() {
+        let mock_get_account = mock("GET", "/sapi/v1/margin/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/get_account.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let account = margin.get_account().unwrap();
+
+        mock_get_account.assert();
+
+        assert!(account.borrow_enabled);
+        assert!(approx_eq!(f64, account.margin_level, 11.64405625, ulps = 2));
+        assert!(approx_eq!(
+            f64,
+            account.total_asset_of_btc,
+            6.82728457,
+            ulps = 2
+        ));
+        assert_eq!(account.user_assets.len(), 2);
+
+        let bnb = &account.user_assets[1];
+        assert_eq!(bnb.asset, "BNB");
+        assert!(approx_eq!(f64, bnb.borrowed, 201.66666672, ulps = 2));
+        assert!(approx_eq!(f64, bnb.net_asset, 2144.83333328, ulps = 2));
+    }
+
+    #[test]
+    fn get_isolated_account() {
+        let mock_get_isolated_account = mock("GET", "/sapi/v1/margin/isolated/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&symbols=BTCUSDT,BNBUSDT&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/get_isolated_account.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let account = margin
+            .get_isolated_account(&["BTCUSDT", "BNBUSDT"])
+            .unwrap();
+
+        mock_get_isolated_account.assert();
+
+        assert!(account.total_asset_of_btc.is_none());
+        assert_eq!(account.assets.len(), 1);
+
+        let asset = &account.assets[0];
+        assert_eq!(asset.symbol, "BTCUSDT");
+        assert_eq!(asset.base_asset.asset, "BTC");
+        assert_eq!(asset.quote_asset.asset, "USDT");
+        assert_eq!(asset.margin_level_status, "EXCESSIVE");
+        assert!(approx_eq!(f64, asset.liquidate_price, 1000.0, ulps = 2));
+    }
+
+    #[test]
+    fn enable_isolated_account() {
+        let mock_enable = mock("POST", "/sapi/v1/margin/isolated/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/enable_isolated_account.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let status = margin.enable_isolated_account("BTCUSDT").unwrap();
+
+        mock_enable.assert();
+
+        assert!(status.success);
+        assert_eq!(status.symbol, "BTCUSDT");
+    }
+
+    #[test]
+    fn new_order() {
+        let mock_new_order = mock("POST", "/sapi/v1/margin/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "isIsolated=TRUE&quantity=10&recvWindow=1234&side=SELL&sideEffectType=AUTO_REPAY&symbol=BTCUSDT&timestamp=\\d+&type=MARKET&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/new_order.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let transaction = margin
+            .new_order(MarginOrderRequest {
+                symbol: "BTCUSDT".into(),
+                is_isolated: true,
+                side: OrderSide::Sell,
+                order_type: OrderType::Market,
+                qty: Some(10.0),
+                quote_order_qty: None,
+                price: None,
+                stop_price: None,
+                time_in_force: None,
+                new_client_order_id: None,
+                side_effect_type: Some(SideEffectType::AutoRepay),
+            })
+            .unwrap();
+
+        mock_new_order.assert();
+
+        assert_eq!(transaction.symbol, "BTCUSDT");
+        assert_eq!(transaction.order_id, 28);
+        assert!(transaction.is_isolated);
+        assert_eq!(transaction.margin_buy_borrow_asset, Some("BTC".into()));
+        assert!(approx_eq!(
+            f64,
+            transaction.margin_buy_borrow_amount.unwrap(),
+            5.0,
+            ulps = 2
+        ));
+        let fills = transaction.fills.unwrap();
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].trade_id, Some(56));
+    }
+
+    #[test]
+    fn new_oco_order() {
+        let mock_new_oco_order = mock("POST", "/sapi/v1/margin/order/oco")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "price=0.036435&quantity=0.624363&recvWindow=1234&side=BUY&stopPrice=0.960664&symbol=LTCBTC&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/new_oco_order.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order_list = margin
+            .new_oco_order(MarginOcoOrderRequest {
+                symbol: "LTCBTC".into(),
+                is_isolated: false,
+                side: OrderSide::Buy,
+                qty: 0.624363,
+                price: 0.036435,
+                stop_price: 0.960664,
+                stop_limit_price: None,
+                stop_limit_time_in_force: None,
+                list_client_order_id: None,
+                side_effect_type: None,
+            })
+            .unwrap();
+
+        mock_new_oco_order.assert();
+
+        assert_eq!(order_list.contingency_type, "OCO");
+        assert_eq!(order_list.is_isolated, Some(false));
+        assert_eq!(order_list.orders.len(), 2);
+        assert_eq!(order_list.order_reports.len(), 2);
+
+        let stop_loss = &order_list.order_reports[0];
+        assert_eq!(stop_loss.type_name, "STOP_LOSS");
+        assert!(approx_eq!(f64, stop_loss.stop_price, 0.960664, ulps = 2));
+
+        let limit_maker = &order_list.order_reports[1];
+        assert_eq!(limit_maker.type_name, "LIMIT_MAKER");
+        assert!(approx_eq!(f64, limit_maker.price, 0.036435, ulps = 2));
+    }
+
+    #[test]
+    fn trade_history() {
+        let mock_trade_history = mock("GET", "/sapi/v1/margin/myTrades")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "limit=10&recvWindow=1234&symbol=BNBBTC&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/trade_history.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let trades = margin
+            .trade_history("BNBBTC", false, None, None, None, 10)
+            .unwrap();
+
+        mock_trade_history.assert();
+
+        assert_eq!(trades.len(), 1);
+        let trade = &trades[0];
+        assert_eq!(trade.id, 34);
+        assert_eq!(trade.order_id, 39324);
+        assert!(approx_eq!(f64, trade.qty, 3.0, ulps = 2));
+        assert!(!trade.is_isolated);
+    }
+
+    #[test]
+    fn get_max_borrowable() {
+        let mock_max_borrowable = mock("GET", "/sapi/v1/margin/maxBorrowable")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "asset=BTC&isolatedSymbol=BTCUSDT&recvWindow=1234&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/get_max_borrowable.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let max_borrowable = margin
+            .get_max_borrowable("BTC", Some("BTCUSDT".into()))
+            .unwrap();
+
+        mock_max_borrowable.assert();
+
+        assert!(approx_eq!(f64, max_borrowable.amount, 1.69248805, ulps = 2));
+        assert!(approx_eq!(f64, max_borrowable.borrow_limit, 60.0, ulps = 2));
+    }
+
+    #[test]
+    fn get_interest_history() {
+        let mock_interest_history = mock("GET", "/sapi/v1/margin/interestHistory")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "asset=USDT&current=1&recvWindow=1234&size=10&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/get_interest_history.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let history = margin
+            .get_interest_history(Some("USDT".into()), None, None, None, 1, 10)
+            .unwrap();
+
+        mock_interest_history.assert();
+
+        assert_eq!(history.total, 1);
+        let entry = &history.rows[0];
+        assert_eq!(entry.tx_id, 1352286576452864727);
+        assert_eq!(entry.interest_type, "ON_BORROW");
+        assert_eq!(entry.isolated_symbol, Some("BNBUSDT".into()));
+        assert!(approx_eq!(f64, entry.interest, 0.00024995, ulps = 2));
+    }
+
+    #[test]
+    fn start_user_data_stream() {
+        let mock_start = mock("POST", "/sapi/v1/userDataStream")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/margin/start_user_data_stream.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let user_data_stream = margin.start_user_data_stream().unwrap();
+
+        mock_start.assert();
+
+        assert_eq!(
+            user_data_stream.listen_key,
+            "T3ee22BIYuWqmvne0HNq2A2WsFlEtLhvWCtItw6ffhhdmjifQ2tRbuKkTHhr"
+        );
+    }
+
+    #[test]
+    fn isolated_user_data_stream() {
+        let mock_start = mock("POST", "/sapi/v1/userDataStream/isolated")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("^symbol=BTCUSDT$".into()))
+            .with_body_from_file("tests/mocks/margin/start_user_data_stream.json")
+            .create();
+        let mock_keep_alive = mock("PUT", "/sapi/v1/userDataStream/isolated")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^listenKey=listen-key&symbol=BTCUSDT$".into(),
+            ))
+            .with_body("{}")
+            .create();
+        let mock_close = mock("DELETE", "/sapi/v1/userDataStream/isolated")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^listenKey=listen-key&symbol=BTCUSDT$".into(),
+            ))
+            .with_body("{}")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let user_data_stream = margin.start_isolated_user_data_stream("BTCUSDT").unwrap();
+        margin
+            .keep_alive_isolated_user_data_stream("BTCUSDT", "listen-key")
+            .unwrap();
+        margin
+            .close_isolated_user_data_stream("BTCUSDT", "listen-key")
+            .unwrap();
+
+        mock_start.assert();
+        mock_keep_alive.assert();
+        mock_close.assert();
+
+        assert_eq!(
+            user_data_stream.listen_key,
+            "T3ee22BIYuWqmvne0HNq2A2WsFlEtLhvWCtItw6ffhhdmjifQ2tRbuKkTHhr"
+        );
+    }
+}
@@ -332,4 +332,222 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn get_trades() {
+        let mock_get_trades = mock("GET", "/api/v3/trades")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("limit=3&symbol=LTCBTC".into()))
+            .with_body_from_file("tests/mocks/market/get_trades.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(mockito::server_url());
+        let market: Market = Binance::new_with_config(None, None, &config);
+
+        let trades = market.get_trades("LTCBTC", 3).unwrap();
+        mock_get_trades.assert();
+
+        assert_eq!(trades.len(), 3);
+        assert_eq!(trades[0].id, 28457);
+        assert!(approx_eq!(f64, trades[0].price, 4.00000100, ulps = 2));
+        assert!(approx_eq!(f64, trades[0].qty, 12.00000000, ulps = 2));
+        assert!(approx_eq!(f64, trades[0].quote_qty, 48.000012, ulps = 2));
+        assert_eq!(trades[0].time, 1499865549590);
+        assert!(trades[0].is_buyer_maker);
+        assert!(trades[0].is_best_match);
+    }
+
+    #[test]
+    fn get_historical_trades() {
+        let mock_get_historical_trades = mock("GET", "/api/v3/historicalTrades")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_header("x-mbx-apikey", "api_key")
+            .match_query(Matcher::Regex("fromId=28457&limit=3&symbol=LTCBTC$".into()))
+            .with_body_from_file("tests/mocks/market/get_trades.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(mockito::server_url());
+        let market: Market = Binance::new_with_config(Some("api_key".into()), None, &config);
+
+        let trades = market.get_historical_trades("LTCBTC", 28457, 3).unwrap();
+        mock_get_historical_trades.assert();
+
+        assert_eq!(trades.len(), 3);
+        assert_eq!(trades[2].id, 28459);
+        assert!(!trades[1].is_buyer_maker);
+    }
+
+    #[test]
+    fn backfill_trades_by_id() {
+        let mock_get_historical_trades = mock("GET", "/api/v3/historicalTrades")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "fromId=28457&limit=1000&symbol=LTCBTC".into(),
+            ))
+            .with_body_from_file("tests/mocks/market/get_trades.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(mockito::server_url());
+        let market: Market = Binance::new_with_config(Some("api_key".into()), None, &config);
+
+        let trades = market
+            .backfill_trades_by_id("LTCBTC", 28457, 28458)
+            .collect::<binance::errors::Result<Vec<Trade>>>()
+            .unwrap();
+        mock_get_historical_trades.assert();
+
+        assert_eq!(trades.len(), 2);
+        assert_eq!(trades[0].id, 28457);
+        assert_eq!(trades[1].id, 28458);
+    }
+
+    #[test]
+    fn backfill_trades_by_time() {
+        let mock_get_agg_trades = mock("GET", "/api/v3/aggTrades")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "limit=1&startTime=1499865549000&symbol=LTCBTC".into(),
+            ))
+            .with_body_from_file("tests/mocks/market/get_agg_trades.json")
+            .expect(1)
+            .create();
+        let mock_get_historical_trades = mock("GET", "/api/v3/historicalTrades")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "fromId=28457&limit=1000&symbol=LTCBTC".into(),
+            ))
+            .with_body_from_file("tests/mocks/market/get_trades.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(mockito::server_url());
+        let market: Market = Binance::new_with_config(Some("api_key".into()), None, &config);
+
+        let trades = market
+            .backfill_trades_by_time("LTCBTC", 1499865549000, 1499865549600)
+            .collect::<binance::errors::Result<Vec<Trade>>>()
+            .unwrap();
+        mock_get_agg_trades.assert();
+        mock_get_historical_trades.assert();
+
+        assert_eq!(trades.len(), 2);
+        assert_eq!(trades[0].id, 28457);
+        assert_eq!(trades[1].id, 28458);
+    }
+
+    #[test]
+    fn get_prices() {
+        let mock_get_prices = mock("GET", "/api/v3/ticker/price")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "symbols=%5B%22BTCUSDT%22%2C%22BNBUSDT%22%5D".into(),
+            ))
+            .with_body_from_file("tests/mocks/market/get_prices.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(mockito::server_url());
+        let market: Market = Binance::new_with_config(None, None, &config);
+
+        let prices = market.get_prices(&["BTCUSDT", "BNBUSDT"]).unwrap();
+        mock_get_prices.assert();
+
+        assert_eq!(prices.len(), 2);
+        assert_eq!(prices[1].symbol, "BNBUSDT");
+        assert!(approx_eq!(f64, prices[1].price, 214.1, ulps = 2));
+    }
+
+    #[test]
+    fn get_rolling_window_price_stats() {
+        let mock_get_rolling_window_price_stats = mock("GET", "/api/v3/ticker")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "symbol=BNBBTC&type=FULL&windowSize=2d".into(),
+            ))
+            .with_body_from_file("tests/mocks/market/get_rolling_window_price_stats.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(mockito::server_url());
+        let market: Market = Binance::new_with_config(None, None, &config);
+
+        let stats = market
+            .get_rolling_window_price_stats("BNBBTC", Some("2d"), TickerType::Full)
+            .unwrap();
+        mock_get_rolling_window_price_stats.assert();
+
+        match stats {
+            TickerStatistics::Full(stats) => {
+                assert_eq!(stats.symbol, "BNBBTC");
+                assert!(approx_eq!(f64, stats.price_change, -8.0, ulps = 2));
+                assert!(approx_eq!(
+                    f64,
+                    stats.weighted_avg_price,
+                    2.60427807,
+                    ulps = 2
+                ));
+                assert_eq!(stats.open_time, 1641859200000);
+                assert_eq!(stats.count, 61);
+            }
+            TickerStatistics::Mini(_) => panic!("expected FULL ticker statistics"),
+        }
+    }
+
+    #[test]
+    fn get_trading_day_price_stats_for_symbols() {
+        let mock_get_trading_day_price_stats = mock("GET", "/api/v3/ticker/tradingDay")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "symbols=%5B%22BTCUSDT%22%2C%22BNBUSDT%22%5D&timeZone=2&type=MINI".into(),
+            ))
+            .with_body_from_file("tests/mocks/market/get_trading_day_mini_price_stats.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(mockito::server_url());
+        let market: Market = Binance::new_with_config(None, None, &config);
+
+        let stats = market
+            .get_trading_day_price_stats_for_symbols(
+                &["BTCUSDT", "BNBUSDT"],
+                Some("2"),
+                TickerType::Mini,
+            )
+            .unwrap();
+        mock_get_trading_day_price_stats.assert();
+
+        assert_eq!(stats.len(), 2);
+        match &stats[1] {
+            TickerStatistics::Mini(stats) => {
+                assert_eq!(stats.symbol, "BNBUSDT");
+                assert!(approx_eq!(f64, stats.last_price, 214.1, ulps = 2));
+                assert_eq!(stats.last_id, 687114106);
+                assert_eq!(stats.count, 78066);
+            }
+            TickerStatistics::Full(_) => panic!("expected MINI ticker statistics"),
+        }
+    }
+
+    #[test]
+    fn get_ui_klines() {
+        let mock_get_ui_klines = mock("GET", "/api/v3/uiKlines")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "interval=5m&limit=10&symbol=LTCBTC&timeZone=-1:00".into(),
+            ))
+            .with_body_from_file("tests/mocks/market/get_klines.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(mockito::server_url());
+        let market: Market = Binance::new_with_config(None, None, &config);
+
+        let klines = market
+            .get_ui_klines("LTCBTC", "5m", 10, None, None, "-1:00".to_string())
+            .unwrap();
+        mock_get_ui_klines.assert();
+
+        match klines {
+            binance::model::KlineSummaries::AllKlineSummaries(klines) => {
+                assert!(!klines.is_empty());
+                assert_eq!(klines[0].open_time, 1499040000000);
+                assert_eq!(klines[0].close, "0.01577100");
+            }
+        }
+    }
 }
@@ -0,0 +1,325 @@
+use binance::api::*;
+use binance::config::*;
+use binance::options::account::*;
+use binance::options::market::*;
+use binance::futures::heartbeat::CountdownHeartbeat;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+    use float_cmp::*;
+    use binance::account::{OrderSide, TimeInForce};
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn get_mark_prices() {
+        let mock_mark_prices = mock("GET", "/eapi/v1/mark")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("symbol=BTC-240927-60000-C".into()))
+            .with_body_from_file("tests/mocks/options/get_mark_prices.json")
+            .create();
+
+        let config = Config::default().set_options_rest_api_endpoint(mockito::server_url());
+        let market: OptionsMarket = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let mark_prices = market
+            .get_mark_prices(Some("BTC-240927-60000-C".into()))
+            .unwrap();
+
+        mock_mark_prices.assert();
+
+        assert_eq!(mark_prices.len(), 1);
+        let mark_price = &mark_prices[0];
+        assert!(approx_eq!(f64, mark_price.mark_price, 1482.5, ulps = 2));
+        assert!(approx_eq!(f64, mark_price.mark_iv, 0.5103, ulps = 2));
+        assert!(approx_eq!(f64, mark_price.delta, 0.55634, ulps = 2));
+        assert!(approx_eq!(f64, mark_price.theta, -72.61571, ulps = 2));
+    }
+
+    #[test]
+    fn get_klines() {
+        let mock_klines = mock("GET", "/eapi/v1/klines")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "interval=5m&limit=1&symbol=BTC-240927-60000-C".into(),
+            ))
+            .with_body_from_file("tests/mocks/options/get_klines.json")
+            .create();
+
+        let config = Config::default().set_options_rest_api_endpoint(mockito::server_url());
+        let market: OptionsMarket = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let klines = market
+            .get_klines("BTC-240927-60000-C", "5m", 1, None, None)
+            .unwrap();
+
+        mock_klines.assert();
+
+        assert_eq!(klines.len(), 1);
+        assert_eq!(klines[0].open_time, 1659398400000);
+        assert_eq!(klines[0].trade_count, 3);
+        assert!(approx_eq!(f64, klines[0].close, 1100.0, ulps = 2));
+        assert!(approx_eq!(f64, klines[0].amount, 440.5, ulps = 2));
+    }
+
+    #[test]
+    fn get_exercise_history() {
+        let mock_exercise_history = mock("GET", "/eapi/v1/exerciseHistory")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("limit=10&underlying=BTCUSDT".into()))
+            .with_body_from_file("tests/mocks/options/get_exercise_history.json")
+            .create();
+
+        let config = Config::default().set_options_rest_api_endpoint(mockito::server_url());
+        let market: OptionsMarket = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let history = market
+            .get_exercise_history(Some("BTCUSDT".into()), None, None, 10)
+            .unwrap();
+
+        mock_exercise_history.assert();
+
+        assert_eq!(history[0].symbol, "BTC-220121-60000-P");
+        assert_eq!(history[0].strike_result, "REALISTIC_VALUE_STRICKEN");
+        assert!(approx_eq!(
+            f64,
+            history[0].real_strike_price,
+            38844.69652571,
+            ulps = 2
+        ));
+    }
+
+    #[test]
+    fn new_order() {
+        let mock_new_order = mock("POST", "/eapi/v1/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "clientOrderId=hedge1&price=1500&quantity=0.5&recvWindow=1234&side=BUY&symbol=BTC-240927-60000-C&timeInForce=GTC&timestamp=\\d+&type=LIMIT&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/options/new_order.json")
+            .create();
+
+        let config = Config::default()
+            .set_options_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: OptionsAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order = OptionsOrderRequest {
+            client_order_id: Some("hedge1".into()),
+            ..OptionsOrderRequest::limit(
+                "BTC-240927-60000-C",
+                OrderSide::Buy,
+                0.5,
+                1500.0,
+                TimeInForce::GTC,
+            )
+        };
+        let order = account.new_order(order).unwrap();
+
+        mock_new_order.assert();
+
+        assert_eq!(order.order_id, 4611875134427365377);
+        assert_eq!(order.status, "ACCEPTED");
+        assert_eq!(order.option_side, "CALL");
+        assert_eq!(order.client_order_id.as_deref(), Some("hedge1"));
+        assert!(approx_eq!(f64, order.quantity, 0.5, ulps = 2));
+    }
+
+    #[test]
+    fn new_batch_orders() {
+        let mock_batch_orders = mock("POST", "/eapi/v1/batchOrders")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "orders=%5B%7B%22price%22%3A%221500%22%2C%22quantity%22%3A%220.5%22%2C%22side%22%3A%22BUY%22%2C%22symbol%22%3A%22BTC-240927-60000-C%22%2C%22timeInForce%22%3A%22GTC%22%2C%22type%22%3A%22LIMIT%22%7D%2C%7B.*%7D%5D&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/options/new_batch_orders.json")
+            .create();
+
+        let config = Config::default()
+            .set_options_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: OptionsAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let orders = vec![
+            OptionsOrderRequest::limit(
+                "BTC-240927-60000-C",
+                OrderSide::Buy,
+                0.5,
+                1500.0,
+                TimeInForce::GTC,
+            ),
+            OptionsOrderRequest {
+                post_only: Some(true),
+                ..OptionsOrderRequest::limit(
+                    "BTC-240927-60000-P",
+                    OrderSide::Sell,
+                    0.5,
+                    900.0,
+                    TimeInForce::GTC,
+                )
+            },
+        ];
+        let results = account.new_batch_orders(orders).unwrap();
+
+        mock_batch_orders.assert();
+
+        assert_eq!(results.len(), 2);
+        match &results[0] {
+            Ok(order) => assert_eq!(order.order_id, 4612288550799409153),
+            Err(_) => panic!("First order should be placed"),
+        }
+        match &results[1] {
+            Err(error) => assert_eq!(error.code, -2010),
+            Ok(_) => panic!("Second order should be rejected"),
+        }
+    }
+
+    #[test]
+    fn new_batch_orders_too_many() {
+        let config = Config::default().set_options_rest_api_endpoint(mockito::server_url());
+        let account: OptionsAccount = Binance::new_with_config(None, None, &config);
+        let orders = (0..OPTIONS_BATCH_ORDERS_LIMIT + 1)
+            .map(|_| OptionsOrderRequest::market("BTC-240927-60000-C", OrderSide::Buy, 0.1))
+            .collect();
+
+        assert!(account.new_batch_orders(orders).is_err());
+    }
+
+    #[test]
+    fn get_margin_account() {
+        let mock_margin_account = mock("GET", "/eapi/v1/marginAccount")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/options/get_margin_account.json")
+            .create();
+
+        let config = Config::default()
+            .set_options_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: OptionsAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let margin_account = account.get_margin_account().unwrap();
+
+        mock_margin_account.assert();
+
+        assert_eq!(margin_account.assets[0].asset, "USDT");
+        assert!(approx_eq!(
+            f64,
+            margin_account.assets[0].unrealized_pnl,
+            -5.00138,
+            ulps = 2
+        ));
+        assert_eq!(margin_account.greeks[0].underlying, "BTCUSDT");
+        assert!(approx_eq!(
+            f64,
+            margin_account.greeks[0].delta,
+            -0.05,
+            ulps = 2
+        ));
+        assert_eq!(margin_account.risk_level.as_deref(), Some("NORMAL"));
+    }
+
+    #[test]
+    fn countdown_cancel_all() {
+        let mock_countdown = mock("POST", "/eapi/v1/countdownCancelAll")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "countdownTime=30000&recvWindow=1234&timestamp=\\d+&underlying=BTCUSDT&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/options/countdown_cancel_all.json")
+            .create();
+
+        let config = Config::default()
+            .set_options_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: OptionsAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let countdown = account.countdown_cancel_all("BTCUSDT", 30000).unwrap();
+
+        mock_countdown.assert();
+
+        assert_eq!(countdown.underlying, "BTCUSDT");
+        assert_eq!(countdown.countdown_time, 30000);
+    }
+
+    #[test]
+    fn countdown_cancel_all_heartbeat() {
+        let mock_heartbeat = mock("POST", "/eapi/v1/countdownCancelAllHeartBeat")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&underlyings=BTCUSDT,ETHUSDT&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/options/countdown_cancel_all_heartbeat.json")
+            .create();
+
+        let config = Config::default()
+            .set_options_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: OptionsAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let heartbeat = account
+            .countdown_cancel_all_heartbeat(&["BTCUSDT", "ETHUSDT"])
+            .unwrap();
+
+        mock_heartbeat.assert();
+
+        assert_eq!(heartbeat.underlyings, vec!["BTCUSDT", "ETHUSDT"]);
+    }
+
+    #[test]
+    fn countdown_heartbeat_sends_heartbeats() {
+        let mock_countdown = mock("POST", "/eapi/v1/countdownCancelAll")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "countdownTime=5000&recvWindow=1234&timestamp=\\d+&underlying=BTCUSDT&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/options/countdown_cancel_all.json")
+            .expect(1)
+            .create();
+        let mock_heartbeat = mock("POST", "/eapi/v1/countdownCancelAllHeartBeat")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&underlyings=BTCUSDT&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/options/countdown_cancel_all_heartbeat.json")
+            .expect_at_least(2)
+            .create();
+        let mock_disable = mock("POST", "/eapi/v1/countdownCancelAll")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "countdownTime=0&recvWindow=1234&timestamp=\\d+&underlying=BTCUSDT&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/options/countdown_cancel_all.json")
+            .create();
+
+        let config = Config::default()
+            .set_options_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: OptionsAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let heartbeat = CountdownHeartbeat::start(
+            account,
+            vec!["BTCUSDT".into()],
+            Duration::from_secs(5),
+            Duration::from_millis(20),
+        )
+        .unwrap();
+        for _ in 0..10 {
+            heartbeat.alive();
+            thread::sleep(Duration::from_millis(20));
+        }
+        assert_eq!(heartbeat.last_error(), None);
+        heartbeat.stop().unwrap();
+
+        mock_countdown.assert();
+        mock_heartbeat.assert();
+        mock_disable.assert();
+    }
+}
@@ -0,0 +1,733 @@
+use binance::api::*;
+use binance::config::*;
+use binance::savings::*;
+use binance::account::*;
+use binance::futures::account::*;
+use binance::futures::coin_m::userstream::CoinMUserStream;
+use binance::futures::userstream::FuturesUserStream;
+use binance::model::*;
+use binance::options::userstream::OptionsUserStream;
+use binance::preflight::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+    use float_cmp::*;
+
+    const BNB_ADDRESS: &str = "bnb136ns6lfw4zs5hg4n85vdthaad7hq5m4gtkgf23";
+
+    fn bnb_withdraw_request(amount: f64, address_tag: Option<String>) -> WithdrawRequest {
+        WithdrawRequest {
+            coin: "BNB".into(),
+            network: None,
+            address: BNB_ADDRESS.into(),
+            address_tag,
+            amount,
+            withdraw_order_id: None,
+            wallet_type: None,
+        }
+    }
+
+    #[test]
+    fn withdraw() {
+        let mock_withdraw = mock("POST", "/sapi/v1/capital/withdraw/apply")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "address=0x6915f16f8791d0a1cc2bf47c13a6b2a92000504b&amount=0.5&coin=BNB&network=BSC&recvWindow=1234&timestamp=\\d+&walletType=1&withdrawOrderId=withdraw-1&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/withdraw.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let withdraw = savings
+            .withdraw(WithdrawRequest {
+                coin: "BNB".into(),
+                network: Some("BSC".into()),
+                address: "0x6915f16f8791d0a1cc2bf47c13a6b2a92000504b".into(),
+                address_tag: None,
+                amount: 0.5,
+                withdraw_order_id: Some("withdraw-1".into()),
+                wallet_type: Some(WalletType::Funding),
+            })
+            .unwrap();
+
+        mock_withdraw.assert();
+
+        assert_eq!(withdraw.id, "7213fea8e94b4a5593d507237e5a555b");
+    }
+
+    #[test]
+    fn withdraw_encodes_free_form_values() {
+        let mock_withdraw = mock("POST", "/sapi/v1/capital/withdraw/apply")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^address=0x6915f16f8791d0a1cc2bf47c13a6b2a92000504b%26amount%3D100&addressTag=memo%3D1%26x&amount=0.5&coin=BNB&recvWindow=1234&timestamp=\\d+&withdrawOrderId=a%26b&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/withdraw.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        savings
+            .withdraw(WithdrawRequest {
+                coin: "BNB".into(),
+                network: None,
+                address: "0x6915f16f8791d0a1cc2bf47c13a6b2a92000504b&amount=100".into(),
+                address_tag: Some("memo=1&x".into()),
+                amount: 0.5,
+                withdraw_order_id: Some("a&b".into()),
+                wallet_type: None,
+            })
+            .unwrap();
+
+        mock_withdraw.assert();
+    }
+
+    #[test]
+    fn withdraw_checked() {
+        let mock_get_all_coins = mock("GET", "/sapi/v1/capital/config/getall")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/get_all_coins.json")
+            .create();
+        let mock_withdraw = mock("POST", "/sapi/v1/capital/withdraw/apply")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(format!(
+                "address={}&addressTag=12345&amount=0.25&coin=BNB&recvWindow=1234&timestamp=\\d+&signature=.*",
+                BNB_ADDRESS
+            )))
+            .with_body_from_file("tests/mocks/savings/withdraw.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let allow_list =
+            WithdrawAllowList::new().allow("BNB", "BNB", BNB_ADDRESS, Some("12345".into()));
+        let withdraw = savings
+            .withdraw_checked(
+                bnb_withdraw_request(0.25, Some("12345".into())),
+                &allow_list,
+            )
+            .unwrap();
+
+        mock_get_all_coins.assert();
+        mock_withdraw.assert();
+
+        assert_eq!(withdraw.id, "7213fea8e94b4a5593d507237e5a555b");
+    }
+
+    #[test]
+    fn withdraw_checked_address_not_allowed() {
+        let mock_get_all_coins = mock("GET", "/sapi/v1/capital/config/getall")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/get_all_coins.json")
+            .create();
+        let mock_withdraw = mock("POST", "/sapi/v1/capital/withdraw/apply")
+            .with_body_from_file("tests/mocks/savings/withdraw.json")
+            .expect(0)
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        // Same address, but allowed without the tag
+        let allow_list = WithdrawAllowList::new().allow("BNB", "BNB", BNB_ADDRESS, None);
+        let result = savings.withdraw_checked(
+            bnb_withdraw_request(0.25, Some("12345".into())),
+            &allow_list,
+        );
+
+        mock_get_all_coins.assert();
+        mock_withdraw.assert();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn validate_withdraw_limits() {
+        let coins: Vec<CoinInfo> = serde_json::from_str(
+            &std::fs::read_to_string("tests/mocks/savings/get_all_coins.json").unwrap(),
+        )
+        .unwrap();
+        let bnb = &coins[0];
+        let bsc_address = "0x6915f16f8791d0a1cc2bf47c13a6b2a92000504b";
+        let allow_list = WithdrawAllowList::new()
+            .allow("BNB", "BNB", BNB_ADDRESS, Some("12345".into()))
+            .allow("BNB", "BSC", bsc_address, None);
+
+        let bsc_request = |amount: f64, address: &str| WithdrawRequest {
+            coin: "BNB".into(),
+            network: Some("BSC".into()),
+            address: address.into(),
+            address_tag: None,
+            amount,
+            withdraw_order_id: None,
+            wallet_type: None,
+        };
+
+        assert!(validate_withdraw(&bsc_request(0.05, bsc_address), bnb, &allow_list).is_ok());
+        // below withdrawMin
+        assert!(validate_withdraw(&bsc_request(0.001, bsc_address), bnb, &allow_list).is_err());
+        // not a multiple of withdrawIntegerMultiple
+        assert!(validate_withdraw(&bsc_request(0.055, bsc_address), bnb, &allow_list).is_err());
+        // allowed, but does not match addressRegex of the network
+        let allow_list = allow_list.allow("BNB", "BSC", BNB_ADDRESS, None);
+        assert!(validate_withdraw(&bsc_request(0.05, BNB_ADDRESS), bnb, &allow_list).is_err());
+
+        // tag does not match memoRegex
+        let allow_list = allow_list.allow("BNB", "BNB", BNB_ADDRESS, Some("12 34".into()));
+        assert!(validate_withdraw(
+            &bnb_withdraw_request(0.25, Some("12 34".into())),
+            bnb,
+            &allow_list
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn get_deposit_history() {
+        let mock_deposit_history = mock("GET", "/sapi/v1/capital/deposit/hisrec")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "coin=BNB&recvWindow=1234&status=1&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/get_deposit_history.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let deposits = savings
+            .get_deposit_history(
+                Some("BNB".into()),
+                DepositStatus::Success,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        mock_deposit_history.assert();
+
+        assert_eq!(deposits.len(), 1);
+        let deposit = &deposits[0];
+        assert_eq!(deposit.id, "769800519366885376");
+        assert_eq!(deposit.status, DepositStatus::Success);
+        assert_eq!(deposit.network, "BNB");
+        assert_eq!(deposit.address_tag, "101764890");
+        assert_eq!(deposit.confirm_times, "1/1");
+        assert_eq!(deposit.complete_time, Some(1661493146000));
+    }
+
+    #[test]
+    fn get_withdraw_history() {
+        let mock_withdraw_history = mock("GET", "/sapi/v1/capital/withdraw/history")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "limit=10&offset=20&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/get_withdraw_history.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let withdrawals = savings
+            .get_withdraw_history(None, None, None, None, 20, 10)
+            .unwrap();
+
+        mock_withdraw_history.assert();
+
+        assert_eq!(withdrawals.len(), 2);
+        let completed = &withdrawals[0];
+        assert_eq!(completed.status, WithdrawStatus::Completed);
+        assert_eq!(completed.withdraw_order_id, Some("WITHDRAWtest123".into()));
+        assert_eq!(completed.confirm_no, Some(3));
+        assert_eq!(completed.complete_time, Some("2023-03-23 16:52:41".into()));
+
+        let processing = &withdrawals[1];
+        assert_eq!(processing.status, WithdrawStatus::Processing);
+        assert_eq!(processing.network, "BTC");
+        assert!(processing.withdraw_order_id.is_none());
+        assert!(processing.complete_time.is_none());
+    }
+
+    #[test]
+    fn deposit_history_range() {
+        // 100 days starting at 1600000000000 are split into a 90-day and a 10-day window
+        let mock_first_window = mock("GET", "/sapi/v1/capital/deposit/hisrec")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "endTime=1607775999999&limit=1000&offset=0&recvWindow=1234&startTime=1600000000000&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/get_deposit_history_window.json")
+            .create();
+        let mock_second_window = mock("GET", "/sapi/v1/capital/deposit/hisrec")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "endTime=1608640000000&limit=1000&offset=0&recvWindow=1234&startTime=1607776000000&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/get_deposit_history.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let deposits: Vec<DepositRecord> = savings
+            .deposit_history_range(None, 1600000000000, 1608640000000)
+            .collect::<binance::errors::Result<Vec<DepositRecord>>>()
+            .unwrap();
+
+        mock_first_window.assert();
+        mock_second_window.assert();
+
+        assert_eq!(deposits.len(), 2);
+        assert_eq!(deposits[0].coin, "USDT");
+        assert_eq!(deposits[0].status, DepositStatus::CreditedButCannotWithdraw);
+        assert!(deposits[0].complete_time.is_none());
+        assert_eq!(deposits[1].coin, "BNB");
+    }
+
+    #[test]
+    fn universal_transfer() {
+        let mock_transfer = mock("POST", "/sapi/v1/asset/transfer")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "amount=250&asset=USDT&recvWindow=1234&timestamp=\\d+&type=MAIN_UMFUTURE&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/universal_transfer.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let transfer = savings
+            .universal_transfer(TransferType::MainUmFuture, "USDT", 250.0, None, None)
+            .unwrap();
+
+        mock_transfer.assert();
+
+        assert_eq!(transfer.tran_id, 13526853623);
+    }
+
+    #[test]
+    fn get_universal_transfer_history() {
+        let mock_transfer_history = mock("GET", "/sapi/v1/asset/transfer")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "current=1&recvWindow=1234&size=10&timestamp=\\d+&type=MAIN_UMFUTURE&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/get_universal_transfer_history.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let history = savings
+            .get_universal_transfer_history(
+                TransferType::MainUmFuture,
+                None,
+                None,
+                1,
+                10,
+                None,
+                None,
+            )
+            .unwrap();
+
+        mock_transfer_history.assert();
+
+        assert_eq!(history.total, 2);
+        assert_eq!(history.rows.len(), 2);
+        let transfer = &history.rows[1];
+        assert_eq!(transfer.transfer_type, "MAIN_UMFUTURE");
+        assert_eq!(transfer.status, "CONFIRMED");
+        assert_eq!(transfer.tran_id, 11366865406);
+    }
+
+    #[test]
+    fn get_dust_assets() {
+        let mock_dust_assets = mock("POST", "/sapi/v1/asset/dust-btc")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "accountType=SPOT&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/get_dust_assets.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let dust = savings
+            .get_dust_assets(Some(DustAccountType::Spot))
+            .unwrap();
+
+        mock_dust_assets.assert();
+
+        assert!(approx_eq!(
+            f64,
+            dust.total_transfer_bnb,
+            0.01777302,
+            ulps = 2
+        ));
+        assert!(approx_eq!(f64, dust.dribblet_percentage, 0.02, ulps = 2));
+        let ada = &dust.details[0];
+        assert_eq!(ada.asset, "ADA");
+        assert!(approx_eq!(f64, ada.to_btc, 0.00016848, ulps = 2));
+        assert!(approx_eq!(
+            f64,
+            ada.to_bnb_off_exchange,
+            0.01741756,
+            ulps = 2
+        ));
+    }
+
+    #[test]
+    fn dust_transfer() {
+        let mock_dust_transfer = mock("POST", "/sapi/v1/asset/dust")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "asset=ETH&asset=LTC&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/dust_transfer.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let transfer = savings.dust_transfer(&["ETH", "LTC"], None).unwrap();
+
+        mock_dust_transfer.assert();
+
+        assert!(approx_eq!(
+            f64,
+            transfer.total_transfered,
+            1.05127099,
+            ulps = 2
+        ));
+        assert_eq!(transfer.transfer_result.len(), 2);
+        assert_eq!(transfer.transfer_result[1].from_asset, "LTC");
+        assert_eq!(transfer.transfer_result[1].tran_id, 2970932918);
+    }
+
+    #[test]
+    fn get_dust_log() {
+        let mock_dust_log = mock("GET", "/sapi/v1/asset/dribblet")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&startTime=1615000000000&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/get_dust_log.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let dust_log = savings.get_dust_log(1615000000000, None).unwrap();
+
+        mock_dust_log.assert();
+
+        assert_eq!(dust_log.total, 1);
+        let entry = &dust_log.user_asset_dribblets[0];
+        assert_eq!(entry.trans_id, 45178372831);
+        assert_eq!(entry.user_asset_dribblet_details.len(), 2);
+        assert_eq!(entry.user_asset_dribblet_details[1].from_asset, "ETH");
+    }
+
+    #[test]
+    fn get_trade_fees() {
+        let mock_trade_fees = mock("GET", "/sapi/v1/asset/tradeFee")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&symbol=ADABNB&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/get_trade_fees.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let trade_fees = savings.get_trade_fees(Some("ADABNB".into())).unwrap();
+
+        mock_trade_fees.assert();
+
+        assert_eq!(trade_fees[0].symbol, "ADABNB");
+        assert!(approx_eq!(
+            f64,
+            trade_fees[0].maker_commission,
+            0.001,
+            ulps = 2
+        ));
+        assert!(approx_eq!(
+            f64,
+            trade_fees[0].taker_commission,
+            0.001,
+            ulps = 2
+        ));
+    }
+
+    #[test]
+    fn get_user_assets() {
+        let mock_user_assets = mock("POST", "/sapi/v3/asset/getUserAsset")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "needBtcValuation=true&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/get_user_assets.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let assets = savings.get_user_assets(None, true).unwrap();
+
+        mock_user_assets.assert();
+
+        assert_eq!(assets.len(), 2);
+        assert_eq!(assets[0].asset, "AVAX");
+        assert!(approx_eq!(f64, assets[0].free, 1.0, ulps = 2));
+        assert!(approx_eq!(
+            f64,
+            assets[0].btc_valuation,
+            0.00061562,
+            ulps = 2
+        ));
+        assert_eq!(assets[0].ipoable, Some(0.0));
+    }
+
+    fn mock_preflight_requests() -> Vec<mockito::Mock> {
+        mock_preflight_requests_with("get_api_restrictions")
+    }
+
+    fn mock_preflight_requests_with(restrictions_file: &str) -> Vec<mockito::Mock> {
+        let requests = [
+            ("/sapi/v1/account/apiRestrictions", restrictions_file),
+            ("/sapi/v1/account/status", "get_account_status"),
+            (
+                "/sapi/v1/account/apiTradingStatus",
+                "get_api_trading_status",
+            ),
+        ];
+        requests
+            .iter()
+            .map(|(path, mock_file)| {
+                mock("GET", *path)
+                    .with_header("content-type", "application/json;charset=UTF-8")
+                    .match_query(Matcher::Regex(
+                        "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+                    ))
+                    .with_body_from_file(format!("tests/mocks/savings/{}.json", mock_file))
+                    .create()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn get_api_trading_status() {
+        let mock_trading_status = mock("GET", "/sapi/v1/account/apiTradingStatus")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/get_api_trading_status.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let trading_status = savings.get_api_trading_status().unwrap();
+
+        mock_trading_status.assert();
+
+        assert!(!trading_status.data.is_locked);
+        assert!(approx_eq!(
+            f64,
+            trading_status.data.trigger_condition["UFR"],
+            300.0,
+            ulps = 2
+        ));
+        let indicator = &trading_status.data.indicators["BTCUSDT"][0];
+        assert_eq!(indicator.indicator, "UFR");
+        assert_eq!(indicator.count, 20);
+        assert!(approx_eq!(f64, indicator.trigger_value, 0.995, ulps = 2));
+    }
+
+    #[test]
+    fn preflight() {
+        let mocks = mock_preflight_requests();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let preflight = Preflight::new()
+            .client::<Account>()
+            .client::<Savings>()
+            .permission(ApiPermission::UniversalTransfer);
+        let restrictions = preflight.run(&savings).unwrap();
+
+        for mock in mocks {
+            mock.assert();
+        }
+
+        assert!(restrictions.ip_restrict);
+        assert!(restrictions.enable_spot_and_margin_trading);
+        assert_eq!(restrictions.trading_authority_expiration_time, None);
+    }
+
+    #[test]
+    fn preflight_user_streams() {
+        let preflight = Preflight::new()
+            .client::<FuturesUserStream>()
+            .client::<CoinMUserStream>()
+            .client::<OptionsUserStream>();
+
+        assert_eq!(
+            preflight.permissions,
+            vec![
+                ApiPermission::Reading,
+                ApiPermission::Futures,
+                ApiPermission::VanillaOptions
+            ]
+        );
+    }
+
+    #[test]
+    fn preflight_missing_permissions() {
+        let mocks = mock_preflight_requests();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let preflight = Preflight::new()
+            .client::<FuturesAccount>()
+            .permission(ApiPermission::Withdrawals);
+        let error = preflight.run(&savings).unwrap_err();
+
+        for mock in mocks {
+            mock.assert();
+        }
+
+        assert_eq!(
+            error.to_string(),
+            "Preflight failed: Futures permission is not enabled; Withdrawals permission is not enabled"
+        );
+    }
+
+    #[test]
+    fn preflight_withdrawals_without_ip_restriction() {
+        let mocks = mock_preflight_requests_with("get_api_restrictions_unrestricted");
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let preflight = Preflight::new().permission(ApiPermission::Withdrawals);
+        let error = preflight.run(&savings).unwrap_err();
+
+        for mock in mocks {
+            mock.assert();
+        }
+
+        assert_eq!(
+            error.to_string(),
+            "Preflight failed: Withdrawals permission requires an IP restricted key"
+        );
+    }
+
+    #[test]
+    fn preflight_key_rejected() {
+        let mock_restrictions = mock("GET", "/sapi/v1/account/apiRestrictions")
+            .with_status(401)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/key_rejected.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let error = Preflight::new().run(&savings).unwrap_err();
+
+        mock_restrictions.assert();
+
+        assert_eq!(
+            error.to_string(),
+            "Preflight failed, the API key was rejected (invalid key or IP address not whitelisted?): Invalid API-key, IP, or permissions for action."
+        );
+    }
+
+    #[test]
+    fn preflight_passes_other_errors() {
+        let mock_restrictions = mock("GET", "/sapi/v1/account/apiRestrictions")
+            .with_status(503)
+            .match_query(Matcher::Any)
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let error = Preflight::new().run(&savings).unwrap_err();
+
+        mock_restrictions.assert();
+
+        assert_eq!(error.to_string(), "Service Unavailable");
+    }
+}
@@ -0,0 +1,205 @@
+use binance::api::*;
+use binance::config::*;
+use binance::model::*;
+use binance::simple_earn::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+    use float_cmp::*;
+
+    #[test]
+    fn get_flexible_products() {
+        let mock_flexible_products = mock("GET", "/sapi/v1/simple-earn/flexible/list")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "asset=BTC&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/simple_earn/get_flexible_products.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let simple_earn: SimpleEarn = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let products = simple_earn
+            .get_flexible_products(Some("BTC".into()), None, None)
+            .unwrap();
+
+        mock_flexible_products.assert();
+
+        assert_eq!(products.total, 1);
+        let product = &products.rows[0];
+        assert_eq!(product.product_id, "BTC001");
+        assert!(approx_eq!(
+            f64,
+            product.latest_annual_percentage_rate,
+            0.05,
+            ulps = 2
+        ));
+        let tiers = product.tier_annual_percentage_rate.as_ref().unwrap();
+        assert!(approx_eq!(f64, tiers["5-10BTC"], 0.03, ulps = 2));
+        assert!(approx_eq!(f64, product.min_purchase_amount, 0.01, ulps = 2));
+    }
+
+    #[test]
+    fn get_locked_products() {
+        let mock_locked_products = mock("GET", "/sapi/v1/simple-earn/locked/list")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "current=1&recvWindow=1234&size=10&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/simple_earn/get_locked_products.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let simple_earn: SimpleEarn = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let products = simple_earn.get_locked_products(None, 1, 10).unwrap();
+
+        mock_locked_products.assert();
+
+        let product = &products.rows[0];
+        assert_eq!(product.project_id, "Axs*90");
+        assert_eq!(product.detail.duration, 90);
+        assert_eq!(product.detail.subscription_start_time, 1646182276000);
+        assert_eq!(product.detail.extra_reward_apr, Some(0.23));
+        assert!(approx_eq!(f64, product.quota.minimum, 0.001, ulps = 2));
+    }
+
+    #[test]
+    fn subscribe_locked() {
+        let mock_subscribe = mock("POST", "/sapi/v1/simple-earn/locked/subscribe")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "amount=10&autoSubscribe=false&projectId=Axs\\*90&recvWindow=1234&sourceAccount=FUND&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/simple_earn/subscribe_locked.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let simple_earn: SimpleEarn = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let subscription = simple_earn
+            .subscribe_locked("Axs*90", 10.0, Some(false), Some(EarnAccount::Fund))
+            .unwrap();
+
+        mock_subscribe.assert();
+
+        assert!(subscription.success);
+        assert_eq!(subscription.purchase_id, 40607);
+        assert_eq!(subscription.position_id, Some("12345".into()));
+    }
+
+    #[test]
+    fn redeem_flexible_all() {
+        let mock_redeem = mock("POST", "/sapi/v1/simple-earn/flexible/redeem")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "productId=USDT001&recvWindow=1234&redeemAll=true&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/simple_earn/redeem_flexible.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let simple_earn: SimpleEarn = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let redemption = simple_earn.redeem_flexible("USDT001", None, None).unwrap();
+
+        mock_redeem.assert();
+
+        assert!(redemption.success);
+        assert_eq!(redemption.redeem_id, 40607);
+    }
+
+    #[test]
+    fn get_locked_positions() {
+        let mock_locked_positions = mock("GET", "/sapi/v1/simple-earn/locked/position")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "asset=AXS&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/simple_earn/get_locked_positions.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let simple_earn: SimpleEarn = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let positions = simple_earn
+            .get_locked_positions(Some("AXS".into()), None, None, None, None)
+            .unwrap();
+
+        mock_locked_positions.assert();
+
+        let position = &positions.rows[0];
+        assert_eq!(position.position_id, 123123);
+        assert_eq!(position.duration, 60);
+        assert_eq!(position.purchase_time, 1646182276000);
+        assert!(approx_eq!(f64, position.apy, 0.2032, ulps = 2));
+        assert_eq!(position.position_type, "AUTO");
+        assert!(position.can_redeem_early);
+    }
+
+    #[test]
+    fn flexible_subscription_history() {
+        let mock_first_page = mock(
+            "GET",
+            "/sapi/v1/simple-earn/flexible/history/subscriptionRecord",
+        )
+        .with_header("content-type", "application/json;charset=UTF-8")
+        .match_query(Matcher::Regex(
+            "current=1&productId=USDT001&recvWindow=1234&size=100&timestamp=\\d+&signature=.*"
+                .into(),
+        ))
+        .with_body_from_file("tests/mocks/simple_earn/flexible_subscription_history_page_1.json")
+        .create();
+        let mock_second_page = mock(
+            "GET",
+            "/sapi/v1/simple-earn/flexible/history/subscriptionRecord",
+        )
+        .with_header("content-type", "application/json;charset=UTF-8")
+        .match_query(Matcher::Regex(
+            "current=2&productId=USDT001&recvWindow=1234&size=100&timestamp=\\d+&signature=.*"
+                .into(),
+        ))
+        .with_body_from_file("tests/mocks/simple_earn/flexible_subscription_history_page_2.json")
+        .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let simple_earn: SimpleEarn = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let query = EarnHistoryQuery {
+            product_id: Some("USDT001".into()),
+            // not a filter of the flexible subscription history
+            position_id: Some(1),
+            ..Default::default()
+        };
+        let records = simple_earn
+            .flexible_subscription_history(query)
+            .collect::<binance::errors::Result<Vec<FlexibleSubscriptionRecord>>>()
+            .unwrap();
+
+        mock_first_page.assert();
+        mock_second_page.assert();
+
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0].purchase_id, 26055);
+        assert_eq!(records[0].amt_from_funding, Some(70.0));
+        assert_eq!(records[2].purchase_id, 26057);
+        assert_eq!(records[2].status, "PURCHASING");
+    }
+}
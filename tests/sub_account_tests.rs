@@ -0,0 +1,213 @@
+use binance::api::*;
+use binance::config::*;
+use binance::sub_account::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+    use float_cmp::*;
+
+    #[test]
+    fn get_sub_accounts() {
+        let mock_sub_accounts = mock("GET", "/sapi/v1/sub-account/list")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "email=testsub%2B1%40gmail.com&isFreeze=false&recvWindow=1234&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/sub_account/get_sub_accounts.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let sub_account: SubAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let sub_accounts = sub_account
+            .get_sub_accounts(Some("testsub+1@gmail.com".into()), false, None, None)
+            .unwrap();
+
+        mock_sub_accounts.assert();
+
+        assert_eq!(sub_accounts.sub_accounts.len(), 1);
+        let info = &sub_accounts.sub_accounts[0];
+        assert_eq!(info.email, "testsub+1@gmail.com");
+        assert!(!info.is_freeze);
+        assert_eq!(info.create_time, 1544433328000);
+    }
+
+    #[test]
+    fn get_assets() {
+        let mock_assets = mock("GET", "/sapi/v3/sub-account/assets")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "email=123%40test.com&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/sub_account/get_assets.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let sub_account: SubAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let assets = sub_account.get_assets("123@test.com").unwrap();
+
+        mock_assets.assert();
+
+        assert_eq!(assets.balances.len(), 2);
+        assert_eq!(assets.balances[1].asset, "BNB");
+        assert!(approx_eq!(f64, assets.balances[1].free, 10003.0, ulps = 2));
+    }
+
+    #[test]
+    fn get_futures_summary() {
+        let mock_futures_summary = mock("GET", "/sapi/v2/sub-account/futures/accountSummary")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "futuresType=2&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/sub_account/get_futures_summary_coin_m.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let sub_account: SubAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let summary = sub_account
+            .get_futures_summary(FuturesType::CoinM, None, None)
+            .unwrap();
+
+        mock_futures_summary.assert();
+
+        assert!(summary.future_account_summary_resp.is_none());
+        let coin_m = summary.delivery_account_summary_resp.unwrap();
+        assert!(approx_eq!(
+            f64,
+            coin_m.total_margin_balance_of_btc,
+            25.03221121,
+            ulps = 2
+        ));
+        assert_eq!(coin_m.sub_account_list[0].email, "123@test.com");
+    }
+
+    #[test]
+    fn universal_transfer() {
+        let mock_transfer = mock("POST", "/sapi/v1/sub-account/universalTransfer")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "amount=100&asset=USDT&clientTranId=rebalance-1&fromAccountType=SPOT&recvWindow=1234&timestamp=\\d+&toAccountType=USDT_FUTURE&toEmail=123%40test.com&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/sub_account/universal_transfer.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let sub_account: SubAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let transfer = sub_account
+            .universal_transfer(SubAccountTransferRequest {
+                from_email: None,
+                to_email: Some("123@test.com".into()),
+                from_account_type: SubAccountType::Spot,
+                to_account_type: SubAccountType::UsdtFuture,
+                asset: "USDT".into(),
+                amount: 100.0,
+                symbol: None,
+                client_tran_id: Some("rebalance-1".into()),
+            })
+            .unwrap();
+
+        mock_transfer.assert();
+
+        assert_eq!(transfer.tran_id, 11945860693);
+        assert_eq!(transfer.client_tran_id, Some("rebalance-1".into()));
+    }
+
+    #[test]
+    fn get_universal_transfer_history() {
+        let mock_transfer_history = mock("GET", "/sapi/v1/sub-account/universalTransfer")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "clientTranId=t%26x&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/sub_account/get_universal_transfer_history.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let sub_account: SubAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let history = sub_account
+            .get_universal_transfer_history(None, None, Some("t&x".into()), None, None, None, None)
+            .unwrap();
+
+        mock_transfer_history.assert();
+
+        assert_eq!(history.total_count, 1);
+        let transfer = &history.result[0];
+        assert_eq!(transfer.from_account_type, "USDT_FUTURE");
+        assert_eq!(transfer.to_account_type, "SPOT");
+        assert_eq!(transfer.status, "SUCCESS");
+        assert!(approx_eq!(f64, transfer.amount, 0.01, ulps = 2));
+    }
+
+    #[test]
+    fn enable_futures() {
+        let mock_enable_futures = mock("POST", "/sapi/v1/sub-account/futures/enable")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "email=123%40test.com&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/sub_account/enable_futures.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let sub_account: SubAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let status = sub_account.enable_futures("123@test.com").unwrap();
+
+        mock_enable_futures.assert();
+
+        assert_eq!(status.email, "123@test.com");
+        assert!(status.is_futures_enabled);
+    }
+
+    #[test]
+    fn set_ip_restriction() {
+        let mock_ip_restriction = mock("POST", "/sapi/v2/sub-account/subAccountApi/ipRestriction")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "email=123%40test.com&ipAddress=69.210.67.14,8.34.21.10&recvWindow=1234&status=2&subAccountApiKey=k5V49ldtn4tszj6W3hystegdfvmGbqDzjmkCtpTvC0G74WhK7yd4rfCTo4lShf&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/sub_account/set_ip_restriction.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let sub_account: SubAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let restriction = sub_account
+            .set_ip_restriction(
+                "123@test.com",
+                "k5V49ldtn4tszj6W3hystegdfvmGbqDzjmkCtpTvC0G74WhK7yd4rfCTo4lShf",
+                &["69.210.67.14", "8.34.21.10"],
+            )
+            .unwrap();
+
+        mock_ip_restriction.assert();
+
+        assert_eq!(restriction.status, Some("2".into()));
+        assert!(restriction.ip_restrict.is_none());
+        assert_eq!(restriction.ip_list, vec!["69.210.67.14", "8.34.21.10"]);
+    }
+}
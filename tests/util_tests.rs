@@ -22,6 +22,12 @@ mod tests {
         assert_eq!(result, format!("recvWindow={}", 1234));
     }
 
+    #[test]
+    fn build_symbols_param() {
+        let result = binance::util::build_symbols_param(&["BTCUSDT", "BNBUSDT"]);
+        assert_eq!(result, "%5B%22BTCUSDT%22%2C%22BNBUSDT%22%5D");
+    }
+
     #[test]
     fn build_signed_request() {
         let now = SystemTime::now();